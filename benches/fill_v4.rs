@@ -0,0 +1,34 @@
+//! Compares filling a batch of version-4 UUIDs with [`UUID::fill_v4`],
+//! which draws all random bytes in one call, against a loop of
+//! [`UUID::gen_v4`].
+
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use ps_uuid::UUID;
+
+fn fill_v4(c: &mut Criterion) {
+    let mut group = c.benchmark_group("fill_v4");
+
+    for len in [16, 1_024, 65_536] {
+        let mut out = vec![UUID::nil(); len];
+
+        group.throughput(Throughput::Elements(len as u64));
+
+        group.bench_with_input(BenchmarkId::new("fill_v4", len), &len, |b, _| {
+            b.iter(|| UUID::fill_v4(black_box(&mut out)));
+        });
+        group.bench_with_input(BenchmarkId::new("gen_v4_loop", len), &len, |b, _| {
+            b.iter(|| {
+                for uuid in black_box(&mut out).iter_mut() {
+                    *uuid = UUID::gen_v4();
+                }
+            });
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, fill_v4);
+criterion_main!(benches);
@@ -0,0 +1,44 @@
+//! Compares version-7 generation under contention: [`UUID::gen_v7`], which
+//! locks the global `STATE` mutex, against [`UUID::gen_v7_monotonic`], which
+//! issues timestamps from a lock-free compare-and-swap loop.
+
+use std::{hint::black_box, thread};
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use ps_uuid::{UuidConstructionError, UUID};
+
+const PER_THREAD: u64 = 10_000;
+
+fn generate_on(threads: u64, generate: fn() -> Result<UUID, UuidConstructionError>) {
+    thread::scope(|scope| {
+        for _ in 0..threads {
+            scope.spawn(|| {
+                for _ in 0..PER_THREAD {
+                    black_box(generate().ok());
+                }
+            });
+        }
+    });
+}
+
+fn v7_contention(c: &mut Criterion) {
+    let mut group = c.benchmark_group("v7_contention");
+
+    for threads in [1, 2, 4, 8] {
+        group.throughput(Throughput::Elements(threads * PER_THREAD));
+
+        group.bench_with_input(BenchmarkId::new("gen_v7", threads), &threads, |b, &n| {
+            b.iter(|| generate_on(n, UUID::gen_v7));
+        });
+        group.bench_with_input(
+            BenchmarkId::new("gen_v7_monotonic", threads),
+            &threads,
+            |b, &n| b.iter(|| generate_on(n, UUID::gen_v7_monotonic)),
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, v7_contention);
+criterion_main!(benches);
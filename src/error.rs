@@ -17,6 +17,40 @@ pub enum UuidConstructionError {
     /// The timestamp provided exceeds the representable range.
     #[error("The timestamp provided is too high.")]
     TimestampOverflow,
+
+    /// UUIDs were requested faster than the generator may issue them without
+    /// running too far ahead of the clock.
+    #[error("The generation rate exceeds the clock's capacity.")]
+    RateExceeded,
+
+    /// The [`Builder`](crate::Builder) cannot construct UUIDs of this
+    /// version, or an operation on an existing UUID does not apply to it.
+    #[error("Version {0} UUIDs do not support this operation.")]
+    UnsupportedVersion(u8),
+
+    /// A [`Builder`](crate::Builder) field was supplied that the chosen
+    /// version does not encode.
+    #[error("The {0} field does not apply to the chosen version.")]
+    IrrelevantField(&'static str),
+
+    /// A field value is wider than the bits the UUID layout reserves for it.
+    #[error("The {0} field is out of range.")]
+    FieldOutOfRange(&'static str),
+
+    /// A UUID is not of the version an operation requires.
+    #[error("Expected a version {expected} UUID.")]
+    VersionMismatch {
+        /// The required version.
+        expected: u8,
+        /// The version the UUID encodes, or `None` if it is not of the OSF
+        /// variant.
+        found: Option<u8>,
+    },
+
+    /// A UUID is not of the OSF (RFC 4122) variant, so it encodes no
+    /// version.
+    #[error("The UUID is not of the RFC 4122 variant.")]
+    NotRfc4122,
 }
 
 /// An error that occurs while converting a [`Duration`](std::time::Duration) to UUID timestamp ticks.
@@ -34,6 +68,36 @@ impl From<DurationToTicksError> for UuidConstructionError {
     }
 }
 
+/// An error that occurs while parsing a [`NodeId`](crate::NodeId) from a
+/// string.
+#[derive(Debug, Error, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum NodeIdParseError {
+    /// The environment variable to read the node ID from is unset or not
+    /// valid Unicode.
+    #[error("environment variable is unset or not valid Unicode")]
+    MissingVar,
+
+    /// The input has an invalid length.
+    #[error("invalid length")]
+    InvalidLength,
+
+    /// The input contains an invalid character, such as a non-hex digit or a
+    /// separator other than the first one used.
+    #[error("invalid character `{ch}` at index {idx}")]
+    InvalidCharacter {
+        /// The offending character.
+        ch: char,
+        /// The index of the offending character.
+        idx: usize,
+    },
+}
+
+/// An error that occurs while parsing a [`Variant`](crate::Variant) from its
+/// name.
+#[derive(Debug, Error, Clone, Copy, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[error("unknown UUID variant name")]
+pub struct VariantParseError;
+
 /// An error that occurs while parsing a [`UUID`](crate::UUID) from a string.
 #[allow(clippy::enum_variant_names)]
 #[derive(Debug, Error, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
@@ -58,4 +122,17 @@ pub enum UuidParseError {
     /// The braces are mismatched or misplaced.
     #[error("mismatching or misplaced braces")]
     InvalidBraces,
+
+    /// A version annotation does not match the version the UUID encodes.
+    #[error("annotated version {annotated} does not match the decoded version")]
+    VersionMismatch {
+        /// The version stated by the annotation.
+        annotated: u8,
+        /// The version the UUID encodes, or `None` if it is not of the OSF variant.
+        decoded: Option<u8>,
+    },
+
+    /// The input encodes a value wider than 128 bits.
+    #[error("value out of range")]
+    ValueOutOfRange,
 }
@@ -0,0 +1,45 @@
+use bytemuck::{Pod, Zeroable};
+
+use crate::UUID;
+
+// SAFETY: `UUID` is `#[repr(transparent)]` over `[u8; 16]`, so the all-zero
+// byte pattern is a valid value (the nil UUID).
+unsafe impl Zeroable for UUID {}
+
+// SAFETY: `UUID` is `Copy`, `'static`, `#[repr(transparent)]` over
+// `[u8; 16]`, has no padding, and every byte pattern is a valid value.
+unsafe impl Pod for UUID {}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+    use crate::UUID;
+
+    #[test]
+    fn aligned_buffer_casts_to_uuid() {
+        #[repr(align(16))]
+        struct Aligned([u8; 16]);
+
+        let buffer = Aligned(*UUID::NS_DNS.as_bytes());
+
+        let uuid: &UUID = bytemuck::try_from_bytes(&buffer.0).expect("16 bytes");
+
+        assert_eq!(*uuid, UUID::from_bytes(buffer.0));
+    }
+
+    #[test]
+    fn slices_cast_both_ways() {
+        let ids = [UUID::gen_v4(), UUID::gen_v7().expect("v7 generation")];
+
+        let bytes: &[u8] = bytemuck::cast_slice(&ids);
+        let back: &[UUID] = bytemuck::cast_slice(bytes);
+
+        assert_eq!(bytes.len(), 32);
+        assert_eq!(back, ids);
+    }
+
+    #[test]
+    fn zeroed_is_nil() {
+        assert_eq!(<UUID as bytemuck::Zeroable>::zeroed(), UUID::nil());
+    }
+}
@@ -0,0 +1,131 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use chrono::{DateTime, Utc};
+
+use crate::{UuidConstructionError, STATE, UUID};
+
+impl UUID {
+    /// Extract the embedded timestamp as a [`DateTime<Utc>`].
+    ///
+    /// This is [`UUID::get_timestamp`] converted to `chrono`, with the same
+    /// precision caveats; every instant a UUID can encode lies within the
+    /// range `chrono` supports. Returns `None` if the UUID does not encode a
+    /// timestamp.
+    #[must_use]
+    pub fn get_chrono(&self) -> Option<DateTime<Utc>> {
+        self.get_timestamp().map(DateTime::from)
+    }
+
+    /// Create a version-1 UUID for the instant `dt`, using a random clock
+    /// sequence and the process-wide `NodeId`.
+    ///
+    /// See [`UUID::new_v1`] to choose both explicitly.
+    ///
+    /// # Errors
+    /// - `TimestampBeforeEpoch` is returned if `dt` predates 1582-10-15.
+    /// - `TimestampOverflow` is returned if `dt` exceeds 5236-03-31.
+    pub fn new_v1_at_chrono(dt: DateTime<Utc>) -> Result<Self, UuidConstructionError> {
+        let node_id = *STATE.lock().node_id();
+
+        Self::new_v1(SystemTime::from(dt), rand::random(), node_id)
+    }
+
+    /// Build a **Version 7** UUID for the instant `dt`, filling the random
+    /// bits from the thread-local CSPRNG.
+    ///
+    /// [`UUID::get_chrono`] returns `dt` floored to the millisecond.
+    ///
+    /// # Errors
+    /// - `TimestampBeforeEpoch` is returned if `dt` precedes 1970-01-01.
+    /// - `TimestampOverflow` is returned if `dt` reaches 2⁴⁸ ms
+    ///   (≈ 10889-08-02).
+    pub fn new_v7_at_chrono(dt: DateTime<Utc>) -> Result<Self, UuidConstructionError> {
+        let since_unix = SystemTime::from(dt)
+            .duration_since(UNIX_EPOCH)
+            .map_err(|_| UuidConstructionError::TimestampBeforeEpoch)?;
+
+        Self::new_v7_checked(since_unix, rand::random())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+    use chrono::{DateTime, NaiveDate, TimeDelta, Utc};
+
+    use crate::{UuidConstructionError, UUID};
+
+    /// 2024-02-29 12:34:56 plus `nanos`, in UTC.
+    fn leap_day(nanos: u32) -> DateTime<Utc> {
+        NaiveDate::from_ymd_opt(2024, 2, 29)
+            .and_then(|date| date.and_hms_nano_opt(12, 34, 56, nanos))
+            .expect("valid date and time")
+            .and_utc()
+    }
+
+    #[test]
+    fn v7_round_trips_through_chrono() {
+        let dt = leap_day(789_000_000);
+        let uuid = UUID::new_v7_at_chrono(dt).expect("representable date");
+
+        assert_eq!(uuid.get_version(), Some(7));
+        assert_eq!(uuid.get_chrono(), Some(dt));
+    }
+
+    #[test]
+    fn v7_timestamp_converts_to_chrono_and_back() {
+        let uuid = UUID::gen_v7().expect("generation must succeed");
+        let dt = uuid.get_chrono().expect("v7 carries a timestamp");
+        let again = UUID::new_v7_at_chrono(dt).expect("representable date");
+
+        assert_eq!(again.get_chrono(), Some(dt));
+        assert_eq!(again.as_bytes()[..6], uuid.as_bytes()[..6]);
+    }
+
+    #[test]
+    fn v7_floors_to_the_millisecond() {
+        let uuid = UUID::new_v7_at_chrono(leap_day(789_999_999)).expect("representable date");
+
+        assert_eq!(uuid.get_chrono(), Some(leap_day(789_000_000)));
+    }
+
+    #[test]
+    fn v1_round_trips_at_tick_precision() {
+        let dt = leap_day(123_456_700);
+        let uuid = UUID::new_v1_at_chrono(dt).expect("representable date");
+
+        assert_eq!(uuid.get_version(), Some(1));
+        assert_eq!(uuid.get_chrono(), Some(dt));
+    }
+
+    #[test]
+    fn pre_epoch_v7_is_rejected() {
+        let before_unix = DateTime::UNIX_EPOCH - TimeDelta::nanoseconds(1);
+
+        assert_eq!(
+            UUID::new_v7_at_chrono(before_unix),
+            Err(UuidConstructionError::TimestampBeforeEpoch)
+        );
+    }
+
+    // The test instant precedes 1601-01-01, which only platforms with a
+    // signed clock representation can express.
+    #[cfg(unix)]
+    #[test]
+    fn pre_gregorian_v1_is_rejected() {
+        let before_gregorian = NaiveDate::from_ymd_opt(1582, 10, 14)
+            .and_then(|date| date.and_hms_opt(0, 0, 0))
+            .expect("valid date and time")
+            .and_utc();
+
+        assert_eq!(
+            UUID::new_v1_at_chrono(before_gregorian),
+            Err(UuidConstructionError::TimestampBeforeEpoch)
+        );
+    }
+
+    #[test]
+    fn uuids_without_a_timestamp_yield_none() {
+        assert_eq!(UUID::gen_v4().get_chrono(), None);
+    }
+}
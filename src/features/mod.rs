@@ -1,8 +1,22 @@
+#[cfg(feature = "bytemuck")]
+mod bytemuck;
+#[cfg(feature = "chrono")]
+mod chrono;
 #[cfg(feature = "num_traits")]
 mod num_traits;
+#[cfg(feature = "postgres")]
+mod postgres;
 #[cfg(feature = "rkyv")]
 mod rkyv;
+#[cfg(feature = "rusqlite")]
+mod rusqlite;
 #[cfg(feature = "serde")]
 mod serde;
+#[cfg(feature = "serde")]
+pub mod serde_bytes;
+#[cfg(feature = "serde")]
+pub mod serde_u128;
+#[cfg(feature = "time")]
+mod time;
 #[cfg(feature = "uuid-crate-compat")]
 pub mod uuid_crate_compat;
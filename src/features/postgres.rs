@@ -0,0 +1,92 @@
+use std::error::Error;
+
+use bytes::BytesMut;
+use postgres_types::{accepts, to_sql_checked, FromSql, IsNull, ToSql, Type};
+
+use crate::{UUID, UUID_BYTES};
+
+impl ToSql for UUID {
+    /// Writes the 16 bytes of the `uuid` binary wire format, which is the
+    /// big-endian byte order [`UUID::as_bytes`] returns.
+    fn to_sql(&self, _: &Type, out: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+        out.extend_from_slice(self.as_bytes());
+
+        Ok(IsNull::No)
+    }
+
+    accepts!(UUID);
+    to_sql_checked!();
+}
+
+impl<'a> FromSql<'a> for UUID {
+    /// Reads the 16 bytes of the `uuid` binary wire format.
+    fn from_sql(_: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
+        let bytes: [u8; UUID_BYTES] = raw
+            .try_into()
+            .map_err(|_| format!("invalid UUID length: {}", raw.len()))?;
+
+        Ok(Self::from_bytes(bytes))
+    }
+
+    accepts!(UUID);
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+    use bytes::BytesMut;
+    use postgres_types::{FromSql, IsNull, ToSql, Type};
+
+    use crate::UUID;
+
+    /// Encodes `uuid` as a query parameter would be.
+    fn bind(uuid: UUID) -> BytesMut {
+        let mut out = BytesMut::new();
+
+        let is_null = uuid
+            .to_sql_checked(&Type::UUID, &mut out)
+            .expect("UUID must encode as uuid");
+
+        assert!(matches!(is_null, IsNull::No));
+
+        out
+    }
+
+    #[test]
+    fn parameter_uses_the_big_endian_wire_format() {
+        assert_eq!(&bind(UUID::NS_DNS)[..], UUID::NS_DNS.as_bytes());
+    }
+
+    #[test]
+    fn roundtrips_through_a_parameter_and_a_column() {
+        let uuid = UUID::gen_v4();
+        let wire = bind(uuid);
+
+        let decoded = UUID::from_sql(&Type::UUID, &wire).expect("column must decode");
+
+        assert_eq!(decoded, uuid);
+    }
+
+    #[test]
+    fn only_the_uuid_type_is_accepted() {
+        assert!(<UUID as ToSql>::accepts(&Type::UUID));
+        assert!(<UUID as FromSql>::accepts(&Type::UUID));
+
+        for ty in [Type::BYTEA, Type::TEXT, Type::INT8] {
+            assert!(!<UUID as ToSql>::accepts(&ty), "{ty}");
+            assert!(!<UUID as FromSql>::accepts(&ty), "{ty}");
+        }
+
+        let mut out = BytesMut::new();
+
+        assert!(UUID::NS_DNS.to_sql_checked(&Type::TEXT, &mut out).is_err());
+        assert!(out.is_empty());
+    }
+
+    #[test]
+    fn wrong_length_columns_are_rejected() {
+        for raw in [&[][..], &[0; 15], &[0; 17]] {
+            assert!(UUID::from_sql(&Type::UUID, raw).is_err(), "{raw:?}");
+        }
+    }
+}
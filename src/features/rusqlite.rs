@@ -0,0 +1,113 @@
+use rusqlite::types::{FromSql, FromSqlError, FromSqlResult, ToSql, ToSqlOutput, ValueRef};
+
+use crate::{UUID, UUID_BYTES};
+
+impl ToSql for UUID {
+    /// Binds the UUID as a 16-byte `BLOB`.
+    fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+        Ok(ToSqlOutput::from(&self.as_bytes()[..]))
+    }
+}
+
+impl FromSql for UUID {
+    /// Reads a 16-byte `BLOB`, or `TEXT` in any spelling
+    /// [`FromStr`](std::str::FromStr) accepts, so columns written by other
+    /// tools as canonical strings decode as well.
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        match value {
+            ValueRef::Blob(blob) => {
+                let bytes: [u8; UUID_BYTES] =
+                    blob.try_into().map_err(|_| FromSqlError::InvalidBlobSize {
+                        expected_size: UUID_BYTES,
+                        blob_size: blob.len(),
+                    })?;
+
+                Ok(Self::from_bytes(bytes))
+            }
+            ValueRef::Text(text) => std::str::from_utf8(text)
+                .map_err(|err| FromSqlError::Other(Box::new(err)))?
+                .parse()
+                .map_err(|err| FromSqlError::Other(Box::new(err))),
+            _ => Err(FromSqlError::InvalidType),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+    use rusqlite::{types::FromSqlError, Connection, Error};
+
+    use crate::UUID;
+
+    fn connection() -> Connection {
+        let conn = Connection::open_in_memory().expect("in-memory database");
+
+        conn.execute("CREATE TABLE t (id)", [])
+            .expect("table creation");
+
+        conn
+    }
+
+    #[test]
+    fn roundtrips_as_blob() {
+        let conn = connection();
+        let uuid = UUID::gen_v4();
+
+        conn.execute("INSERT INTO t (id) VALUES (?1)", [uuid])
+            .expect("insert");
+
+        let (stored, typ): (UUID, String) = conn
+            .query_row("SELECT id, typeof(id) FROM t", [], |row| {
+                Ok((row.get(0)?, row.get(1)?))
+            })
+            .expect("select");
+
+        assert_eq!(stored, uuid);
+        assert_eq!(typ, "blob");
+    }
+
+    #[test]
+    fn decodes_text() {
+        let conn = connection();
+
+        conn.execute(
+            "INSERT INTO t (id) VALUES ('6BA7B810-9DAD-11D1-80B4-00C04FD430C8')",
+            [],
+        )
+        .expect("insert");
+
+        let stored: UUID = conn
+            .query_row("SELECT id FROM t", [], |row| row.get(0))
+            .expect("select");
+
+        assert_eq!(stored, UUID::NS_DNS);
+    }
+
+    #[test]
+    fn rejects_wrong_blob_size_and_type() {
+        let conn = connection();
+
+        conn.execute("INSERT INTO t (id) VALUES (x'0102'), (42)", [])
+            .expect("insert");
+
+        let mut stmt = conn.prepare("SELECT id FROM t").expect("prepare");
+        let results: Vec<rusqlite::Result<UUID>> = stmt
+            .query_map([], |row| row.get(0))
+            .expect("query")
+            .collect();
+
+        let Err(Error::FromSqlConversionFailure(_, _, err)) = &results[0] else {
+            panic!("short blob must fail conversion: {:?}", results[0]);
+        };
+
+        assert!(matches!(
+            err.downcast_ref::<FromSqlError>(),
+            Some(FromSqlError::InvalidBlobSize {
+                expected_size: 16,
+                blob_size: 2
+            })
+        ));
+        assert!(matches!(&results[1], Err(Error::InvalidColumnType(..))));
+    }
+}
@@ -24,7 +24,7 @@ impl Serialize for UUID {
     }
 }
 
-struct UUIDVisitor;
+pub(super) struct UUIDVisitor;
 
 impl<'de> Visitor<'de> for UUIDVisitor {
     type Value = UUID;
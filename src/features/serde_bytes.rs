@@ -0,0 +1,112 @@
+//! Serde support for always encoding a [`UUID`] as its 16 raw bytes.
+//!
+//! Use with `#[serde(with = "ps_uuid::serde_bytes")]` on a `UUID` field to
+//! serialize it through [`Serializer::serialize_bytes`] whatever the format's
+//! [`is_human_readable`](Serializer::is_human_readable) flag says. The
+//! format decides how the bytes look: CBOR writes a 16-byte byte string,
+//! while JSON, which has no byte type, writes an array of 16 numbers.
+//! Deserialization accepts the same bytes back, or a sequence of 16 bytes,
+//! but never a UUID string.
+//!
+//! ```
+//! use ps_uuid::UUID;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Row {
+//!     #[serde(with = "ps_uuid::serde_bytes")]
+//!     id: UUID,
+//! }
+//! ```
+
+use serde::{Deserializer, Serializer};
+
+use super::serde::UUIDVisitor;
+use crate::UUID;
+
+/// Serializes `uuid` as its 16 bytes.
+///
+/// # Errors
+///
+/// Returns the error of the underlying serializer.
+pub fn serialize<S>(uuid: &UUID, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_bytes(uuid.as_bytes())
+}
+
+/// Deserializes a [`UUID`] from its 16 bytes.
+///
+/// # Errors
+///
+/// Returns the error of the underlying deserializer, for example if the
+/// input does not hold exactly 16 bytes.
+pub fn deserialize<'de, D>(deserializer: D) -> Result<UUID, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_bytes(UUIDVisitor)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+    use serde::{Deserialize, Serialize};
+
+    use crate::UUID;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Row {
+        #[serde(with = "crate::serde_bytes")]
+        id: UUID,
+    }
+
+    #[test]
+    fn json_writes_a_byte_array_despite_being_human_readable() {
+        let row = Row {
+            id: UUID::from_u128(0x0102),
+        };
+
+        let json = serde_json::to_string(&row).expect("serialization must succeed");
+
+        assert_eq!(json, r#"{"id":[0,0,0,0,0,0,0,0,0,0,0,0,0,0,1,2]}"#);
+        assert_eq!(
+            serde_json::from_str::<Row>(&json).expect("deserialization must succeed"),
+            row
+        );
+    }
+
+    #[test]
+    fn json_rejects_a_uuid_string() {
+        let json = r#"{"id":"6ba7b810-9dad-11d1-80b4-00c04fd430c8"}"#;
+
+        assert!(serde_json::from_str::<Row>(json).is_err());
+    }
+
+    #[test]
+    fn cbor_writes_a_16_byte_string() {
+        let row = Row { id: UUID::NS_DNS };
+
+        let mut cbor = Vec::new();
+        ciborium::into_writer(&row, &mut cbor).expect("serialization must succeed");
+
+        // A map with one entry, the key "id", then a byte string of length 16.
+        let mut expected = vec![0xA1, 0x62, b'i', b'd', 0x50];
+        expected.extend_from_slice(UUID::NS_DNS.as_bytes());
+
+        assert_eq!(cbor, expected);
+
+        let back: Row =
+            ciborium::from_reader(cbor.as_slice()).expect("deserialization must succeed");
+
+        assert_eq!(back, row);
+    }
+
+    #[test]
+    fn wrong_length_is_rejected() {
+        let json = r#"{"id":[1,2,3]}"#;
+
+        assert!(serde_json::from_str::<Row>(json).is_err());
+    }
+}
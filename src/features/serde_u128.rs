@@ -0,0 +1,104 @@
+//! Serde support for encoding a [`UUID`] as a native 128-bit integer.
+//!
+//! Use with `#[serde(with = "ps_uuid::serde_u128")]` on a `UUID` field to
+//! serialize it through [`Serializer::serialize_u128`] instead of as a string
+//! or byte array. The integer is the big-endian value of `u128::from(uuid)`.
+//! Only formats with native 128-bit integers (such as postcard, bincode, or
+//! JSON) can carry it.
+//!
+//! ```
+//! use ps_uuid::UUID;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Row {
+//!     #[serde(with = "ps_uuid::serde_u128")]
+//!     id: UUID,
+//! }
+//! ```
+
+use serde::{Deserializer, Serializer};
+
+use super::serde::UUIDVisitor;
+use crate::UUID;
+
+/// Serializes `uuid` as its big-endian `u128` value.
+///
+/// # Errors
+///
+/// Returns the error of the underlying serializer, for example if the format
+/// does not support 128-bit integers.
+pub fn serialize<S>(uuid: &UUID, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_u128(uuid.to_u128())
+}
+
+/// Deserializes a [`UUID`] from its big-endian `u128` value.
+///
+/// # Errors
+///
+/// Returns the error of the underlying deserializer, for example if the
+/// input is not an unsigned integer.
+pub fn deserialize<'de, D>(deserializer: D) -> Result<UUID, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_u128(UUIDVisitor)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+    use serde::{Deserialize, Serialize};
+
+    use crate::UUID;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Row {
+        #[serde(with = "crate::serde_u128")]
+        id: UUID,
+    }
+
+    #[test]
+    fn round_trip_postcard() {
+        let row = Row { id: UUID::gen_v4() };
+
+        let bytes = postcard::to_allocvec(&row).expect("serialization must succeed");
+        let back: Row = postcard::from_bytes(&bytes).expect("deserialization must succeed");
+
+        assert_eq!(back, row);
+    }
+
+    #[test]
+    fn postcard_encodes_a_varint_u128() {
+        let row = Row {
+            id: UUID::from_u128(1),
+        };
+
+        let bytes = postcard::to_allocvec(&row).expect("serialization must succeed");
+
+        assert_eq!(bytes, [1]);
+    }
+
+    #[test]
+    fn json_writes_a_number() {
+        let row = Row { id: UUID::max() };
+
+        let json = serde_json::to_string(&row).expect("serialization must succeed");
+
+        assert_eq!(json, format!("{{\"id\":{}}}", u128::MAX));
+        assert_eq!(
+            serde_json::from_str::<Row>(&json).expect("deserialization must succeed"),
+            row
+        );
+    }
+
+    #[test]
+    fn rejects_strings() {
+        let json = "{\"id\":\"6ba7b810-9dad-11d1-80b4-00c04fd430c8\"}";
+
+        assert!(serde_json::from_str::<Row>(json).is_err());
+    }
+}
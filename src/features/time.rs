@@ -0,0 +1,124 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use time::OffsetDateTime;
+
+use crate::{UuidConstructionError, UUID};
+
+/// Converts a `SystemTime` into a UTC `OffsetDateTime`, returning `None` if
+/// the instant lies outside the range `time` can represent.
+fn to_offset_date_time(timestamp: SystemTime) -> Option<OffsetDateTime> {
+    let nanos = match timestamp.duration_since(UNIX_EPOCH) {
+        Ok(since_unix) => i128::try_from(since_unix.as_nanos()).ok()?,
+        Err(err) => -i128::try_from(err.duration().as_nanos()).ok()?,
+    };
+
+    OffsetDateTime::from_unix_timestamp_nanos(nanos).ok()
+}
+
+impl UUID {
+    /// Extract the embedded timestamp as a UTC [`OffsetDateTime`].
+    ///
+    /// This is [`UUID::get_timestamp`] in calendar terms, with the same
+    /// precision caveats. Returns `None` if the UUID does not encode a
+    /// timestamp, or if the instant lies beyond the years `time` supports
+    /// (±9999 by default), which far-future version-7 UUIDs can reach.
+    #[must_use]
+    pub fn get_datetime(&self) -> Option<OffsetDateTime> {
+        self.get_timestamp().and_then(to_offset_date_time)
+    }
+
+    /// Build a **Version 7** UUID for the instant `dt`, filling the random
+    /// bits from the thread-local CSPRNG.
+    ///
+    /// The offset of `dt` is irrelevant: the UUID encodes the underlying
+    /// instant, so [`UUID::get_datetime`] returns it in UTC, floored to the
+    /// millisecond.
+    ///
+    /// # Errors
+    /// - `TimestampBeforeEpoch` is returned if `dt` precedes 1970-01-01.
+    /// - `TimestampOverflow` is returned if `dt` reaches 2⁴⁸ ms
+    ///   (≈ 10889-08-02).
+    pub fn new_v7_at(dt: OffsetDateTime) -> Result<Self, UuidConstructionError> {
+        let secs = u64::try_from(dt.unix_timestamp())
+            .map_err(|_| UuidConstructionError::TimestampBeforeEpoch)?;
+
+        Self::new_v7_checked(Duration::new(secs, dt.nanosecond()), rand::random())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+    use time::{Date, Duration, Month, OffsetDateTime, Time, UtcOffset};
+
+    use crate::{UuidConstructionError, UUID};
+
+    /// 2024-02-29 12:34:56 plus `nanos`, at the given hour offset.
+    fn leap_day(nanos: u32, offset_hours: i8) -> OffsetDateTime {
+        let date = Date::from_calendar_date(2024, Month::February, 29).expect("valid date");
+        let time = Time::from_hms_nano(12, 34, 56, nanos).expect("valid time");
+        let offset = UtcOffset::from_hms(offset_hours, 0, 0).expect("valid offset");
+
+        date.with_time(time).assume_utc().to_offset(offset)
+    }
+
+    #[test]
+    fn v7_round_trips_a_known_date() {
+        let dt = leap_day(789_000_000, 0);
+        let uuid = UUID::new_v7_at(dt).expect("representable date");
+
+        assert_eq!(uuid.get_version(), Some(7));
+        assert_eq!(uuid.get_datetime(), Some(dt));
+    }
+
+    #[test]
+    fn offset_is_normalized_to_utc() {
+        let dt = leap_day(789_000_000, 2);
+        let uuid = UUID::new_v7_at(dt).expect("representable date");
+
+        assert_eq!(uuid.get_datetime(), Some(leap_day(789_000_000, 0)));
+    }
+
+    #[test]
+    fn sub_millisecond_part_is_floored() {
+        let dt = leap_day(789_999_999, 0);
+        let uuid = UUID::new_v7_at(dt).expect("representable date");
+
+        assert_eq!(uuid.get_datetime(), Some(leap_day(789_000_000, 0)));
+    }
+
+    #[test]
+    fn v1_decodes_to_its_calendar_date() {
+        // NS_DNS was minted on 1998-02-04.
+        let dt = UUID::NS_DNS.get_datetime().expect("v1 carries a timestamp");
+
+        assert_eq!(
+            dt.date(),
+            Date::from_calendar_date(1998, Month::February, 4).expect("valid date")
+        );
+    }
+
+    #[test]
+    fn uuids_without_a_timestamp_yield_none() {
+        assert_eq!(UUID::gen_v4().get_datetime(), None);
+        assert_eq!(UUID::new_v5(&UUID::NS_DNS, "a").get_datetime(), None);
+    }
+
+    #[test]
+    fn out_of_range_v7_yields_none() {
+        // Year 10889 is beyond the default range of `time`.
+        let uuid = UUID::from_parts_v7((1 << 48) - 1, 0, 0);
+
+        assert_eq!(uuid.get_datetime(), None);
+    }
+
+    #[test]
+    fn pre_epoch_dates_are_rejected() {
+        let dt = OffsetDateTime::UNIX_EPOCH - Duration::nanoseconds(1);
+
+        assert_eq!(
+            UUID::new_v7_at(dt),
+            Err(UuidConstructionError::TimestampBeforeEpoch)
+        );
+    }
+}
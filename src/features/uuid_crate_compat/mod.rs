@@ -97,38 +97,6 @@ impl UUID {
         }
     }
 
-    /// Creates a UUID from four field values in little-endian order.
-    ///
-    /// # Arguments
-    /// * `d1` - The first field (32 bits, little-endian)
-    /// * `d2` - The second field (16 bits, little-endian)
-    /// * `d3` - The third field (16 bits, little-endian)
-    /// * `d4` - The fourth field (64 bits as 8 bytes)
-    #[must_use]
-    #[allow(clippy::cast_possible_truncation)]
-    pub const fn from_fields_le(d1: u32, d2: u16, d3: u16, d4: &[u8; 8]) -> Self {
-        Self {
-            bytes: [
-                d1 as u8,
-                (d1 >> 8) as u8,
-                (d1 >> 16) as u8,
-                (d1 >> 24) as u8,
-                d2 as u8,
-                (d2 >> 8) as u8,
-                d3 as u8,
-                (d3 >> 8) as u8,
-                d4[0],
-                d4[1],
-                d4[2],
-                d4[3],
-                d4[4],
-                d4[5],
-                d4[6],
-                d4[7],
-            ],
-        }
-    }
-
     /// Returns the four field values of the UUID in big-endian order.
     ///
     /// # Returns
@@ -1,7 +1,10 @@
 mod md5;
 mod sha1;
 mod to_hex;
+mod transparent;
 
 pub use md5::{md5, Md5};
 pub use sha1::{sha1, Sha1};
-pub use to_hex::{to_hex, ToHex};
+pub use to_hex::{to_hex, to_hex_upper, ToHex};
+pub(crate) use to_hex::{LUT, LUT_UPPER};
+pub(crate) use transparent::{byte_arrays_mut, uuid_ref};
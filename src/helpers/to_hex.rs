@@ -1,18 +1,29 @@
-const LUT: &[u8; 16] = b"0123456789abcdef";
+pub(crate) const LUT: &[u8; 16] = b"0123456789abcdef";
+pub(crate) const LUT_UPPER: &[u8; 16] = b"0123456789ABCDEF";
 
-/// Encodes a byte slice as a lowercase hexadecimal string.
-#[must_use]
-pub fn to_hex(bytes: &[u8]) -> String {
+fn encode(bytes: &[u8], lut: &[u8; 16]) -> String {
     let mut out = String::with_capacity(bytes.len() * 2);
 
     for &b in bytes {
-        out.push(LUT[(b >> 4) as usize] as char);
-        out.push(LUT[(b & 0x0f) as usize] as char);
+        out.push(lut[(b >> 4) as usize] as char);
+        out.push(lut[(b & 0x0f) as usize] as char);
     }
 
     out
 }
 
+/// Encodes a byte slice as a lowercase hexadecimal string.
+#[must_use]
+pub fn to_hex(bytes: &[u8]) -> String {
+    encode(bytes, LUT)
+}
+
+/// Encodes a byte slice as an uppercase hexadecimal string.
+#[must_use]
+pub fn to_hex_upper(bytes: &[u8]) -> String {
+    encode(bytes, LUT_UPPER)
+}
+
 /// A convenience trait for encoding byte-like values as lowercase hexadecimal strings.
 pub trait ToHex
 where
@@ -20,6 +31,9 @@ where
 {
     /// Encodes `self` as a lowercase hexadecimal string.
     fn to_hex(self) -> String;
+
+    /// Encodes `self` as an uppercase hexadecimal string.
+    fn to_hex_upper(self) -> String;
 }
 
 impl<T> ToHex for T
@@ -29,11 +43,15 @@ where
     fn to_hex(self) -> String {
         to_hex(self.as_ref())
     }
+
+    fn to_hex_upper(self) -> String {
+        to_hex_upper(self.as_ref())
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{to_hex, ToHex};
+    use super::{to_hex, to_hex_upper, ToHex};
 
     #[test]
     fn empty_slice() {
@@ -101,4 +119,23 @@ mod tests {
 
         assert_eq!(hex, bytes.to_hex());
     }
+
+    #[test]
+    fn upper_and_lower_case() {
+        assert_eq!(to_hex_upper(&[0xAB, 0xCD]), "ABCD");
+        assert_eq!(to_hex(&[0xAB, 0xCD]), "abcd");
+    }
+
+    #[test]
+    fn upper_empty_slice() {
+        assert_eq!(to_hex_upper(&[]), "");
+    }
+
+    #[test]
+    fn test_trait_upper() {
+        let bytes = b"\xde\xad\xbe\xef";
+        let hex = "DEADBEEF";
+
+        assert_eq!(hex, bytes.to_hex_upper());
+    }
 }
@@ -0,0 +1,68 @@
+//! The crate's only reinterpretations between [`UUID`] and its byte array.
+//!
+//! `UUID` is `#[repr(transparent)]` over `[u8; UUID_BYTES]`, so the two
+//! share size, alignment (1) and layout, and every byte pattern is a valid
+//! `UUID`. The assertions below pin those facts at compile time.
+
+use crate::{UUID, UUID_BYTES};
+
+const _: () = {
+    assert!(size_of::<UUID>() == UUID_BYTES);
+    assert!(align_of::<UUID>() == align_of::<[u8; UUID_BYTES]>());
+};
+
+/// Views `bytes` as a `UUID` without copying.
+pub(crate) const fn uuid_ref(bytes: &[u8; UUID_BYTES]) -> &UUID {
+    // SAFETY: `UUID` is `#[repr(transparent)]` over `[u8; UUID_BYTES]` and
+    // valid for every byte pattern, and the returned reference borrows
+    // `bytes` for the same lifetime.
+    unsafe { &*core::ptr::from_ref(bytes).cast::<UUID>() }
+}
+
+/// Views `uuids` as their byte arrays without copying, so the whole slice
+/// can be written at once, e.g. through `as_flattened_mut`.
+pub(crate) const fn byte_arrays_mut(uuids: &mut [UUID]) -> &mut [[u8; UUID_BYTES]] {
+    let len = uuids.len();
+
+    // SAFETY: `UUID` is `#[repr(transparent)]` over `[u8; UUID_BYTES]`, so
+    // a slice of `len` UUIDs is a slice of `len` arrays with the same
+    // layout, and any bytes written through it leave valid UUIDs behind.
+    // The returned slice borrows `uuids` exclusively for the same lifetime.
+    unsafe { core::slice::from_raw_parts_mut(uuids.as_mut_ptr().cast(), len) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{byte_arrays_mut, uuid_ref};
+    use crate::UUID;
+
+    #[test]
+    fn uuid_ref_aliases_the_array() {
+        let bytes = [0xA5; 16];
+        let uuid = uuid_ref(&bytes);
+
+        assert_eq!(*uuid, UUID::from_bytes(bytes));
+        assert!(core::ptr::eq(uuid.as_bytes().as_ptr(), bytes.as_ptr()));
+    }
+
+    #[test]
+    fn byte_arrays_mut_writes_through_to_every_uuid() {
+        let mut uuids = [UUID::nil(); 3];
+        let arrays = byte_arrays_mut(&mut uuids);
+
+        assert_eq!(arrays.len(), 3);
+
+        for (byte, value) in arrays.as_flattened_mut().iter_mut().zip(0u8..) {
+            *byte = value;
+        }
+
+        assert_eq!(uuids[0].as_bytes()[0], 0);
+        assert_eq!(uuids[1].as_bytes()[0], 16);
+        assert_eq!(uuids[2].as_bytes()[15], 47);
+    }
+
+    #[test]
+    fn empty_slices_stay_empty() {
+        assert!(byte_arrays_mut(&mut []).is_empty());
+    }
+}
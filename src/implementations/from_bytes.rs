@@ -12,6 +12,14 @@ impl From<UUID> for [u8; UUID_BYTES] {
     }
 }
 
+/// Borrows the UUID's bytes, so `&[u8; 16]` is also reachable through
+/// `TryFrom<&UUID>` (with `Infallible` as the error) in generic code.
+impl<'a> From<&'a UUID> for &'a [u8; UUID_BYTES] {
+    fn from(uuid: &'a UUID) -> Self {
+        uuid.as_bytes()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{UUID, UUID_BYTES};
@@ -33,6 +41,19 @@ mod tests {
         assert_eq!(UUID::from(bytes), UUID::from_bytes(bytes));
     }
 
+    fn generic<'a, T: TryFrom<&'a UUID>>(uuid: &'a UUID) -> Option<T> {
+        T::try_from(uuid).ok()
+    }
+
+    #[test]
+    fn borrowed_array_is_the_uuid_storage() {
+        let uuid = UUID::gen_v4();
+        let arr: &[u8; UUID_BYTES] = (&uuid).into();
+        assert!(core::ptr::eq(arr, uuid.as_bytes()));
+
+        assert_eq!(generic::<&[u8; UUID_BYTES]>(&uuid), Some(uuid.as_bytes()));
+    }
+
     #[test]
     fn into_array_matches_as_bytes() {
         let uuid = UUID::from(0x0123_4567_89ab_cdef_u128);
@@ -12,33 +12,46 @@ impl FromStr for UUID {
     ///   - 32 hex digits without hyphens    `xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx`
     ///   - surrounded by braces             `{…}`  (either of the above)
     ///   - as an URN                        `urn:uuid:<canonical>`
+    ///   - as a 128-bit hex number          `0x` followed by 32 hex digits
+    ///
+    /// The `0x` form maps to the big-endian layout of `From<u128>`; it takes
+    /// neither braces nor hyphens.
     fn from_str(mut s: &str) -> Result<Self, Self::Err> {
-        // 1. Strip leading `urn:uuid:` (case-insensitive).
-        //
-        // Compare on the raw bytes rather than slicing the `&str`: a byte-index
-        // slice such as `s[..URN.len()]` panics when the boundary falls inside a
-        // multi-byte character, so non-ASCII input must not reach it.
-        const URN: &str = "urn:uuid:";
-        if let Some(prefix) = s.as_bytes().get(..URN.len()) {
-            if prefix.eq_ignore_ascii_case(URN.as_bytes()) {
-                s = &s[URN.len()..];
+        // 0. Strip a leading `0x` (case-insensitive); only the bare 32-digit
+        //    form may follow it.
+        let hex_prefixed = matches!(s.as_bytes(), [b'0', b'x' | b'X', ..]);
+
+        if hex_prefixed {
+            s = &s[2..];
+        } else {
+            // 1. Strip leading `urn:uuid:` (case-insensitive).
+            //
+            // Compare on the raw bytes rather than slicing the `&str`: a
+            // byte-index slice such as `s[..URN.len()]` panics when the
+            // boundary falls inside a multi-byte character, so non-ASCII input
+            // must not reach it.
+            const URN: &str = "urn:uuid:";
+            if let Some(prefix) = s.as_bytes().get(..URN.len()) {
+                if prefix.eq_ignore_ascii_case(URN.as_bytes()) {
+                    s = &s[URN.len()..];
+                }
             }
-        }
 
-        // 2. Strip optional surrounding braces.
-        if s.starts_with('{') {
-            if !s.ends_with('}') {
+            // 2. Strip optional surrounding braces.
+            if s.starts_with('{') {
+                if !s.ends_with('}') {
+                    return Err(UuidParseError::InvalidBraces);
+                }
+                s = &s[1..s.len() - 1];
+            } else if s.ends_with('}') {
                 return Err(UuidParseError::InvalidBraces);
             }
-            s = &s[1..s.len() - 1];
-        } else if s.ends_with('}') {
-            return Err(UuidParseError::InvalidBraces);
         }
 
         // 3. Decide expected format.
         let expect_hyphens = match s.len() {
             32 => false,
-            36 => true,
+            36 if !hex_prefixed => true,
             _ => return Err(UuidParseError::InvalidLength),
         };
 
@@ -448,4 +461,46 @@ mod tests {
         let uuid = UUID::from_str(s).expect("failed to parse UUID in positive test case");
         assert_eq!(uuid.bytes, RFC_SAMPLE_BYTES);
     }
+
+    // ---------------------------------------------------------------------
+    // `0x`-prefixed 128-bit hex form
+    // ---------------------------------------------------------------------
+
+    #[test]
+    fn parses_hex_prefixed() {
+        for s in [
+            "0x6ba7b8109dad11d180b400c04fd430c8",
+            "0X6BA7B8109DAD11D180B400C04FD430C8",
+        ] {
+            let uuid = UUID::from_str(s).expect("failed to parse UUID in positive test case");
+            assert_eq!(uuid.bytes, RFC_SAMPLE_BYTES, "parsing failed for {s}");
+        }
+    }
+
+    #[test]
+    fn hex_prefixed_matches_from_u128() {
+        let uuid = UUID::from_str("0x00000000000000000000000000000001")
+            .expect("failed to parse UUID in positive test case");
+        assert_eq!(uuid, UUID::from(1u128));
+    }
+
+    #[test]
+    fn rejects_hex_prefix_with_other_decorations() {
+        assert_eq!(
+            UUID::from_str("0x6ba7b810-9dad-11d1-80b4-00c04fd430c8"),
+            Err(UuidParseError::InvalidLength)
+        );
+        assert_eq!(
+            UUID::from_str("0x{6ba7b8109dad11d180b400c04fd430c8}"),
+            Err(UuidParseError::InvalidLength)
+        );
+        assert_eq!(
+            UUID::from_str("0x6ba7b8109dad11d180b400c04fd430c"),
+            Err(UuidParseError::InvalidLength)
+        );
+        assert_eq!(
+            UUID::from_str("{0x6ba7b8109dad11d180b400c04fd430c8}"),
+            Err(UuidParseError::InvalidLength)
+        );
+    }
 }
@@ -8,4 +8,5 @@ mod from_bytes;
 mod from_int;
 mod from_str;
 mod ops;
+mod sum;
 mod try_from;
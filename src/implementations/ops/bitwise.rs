@@ -306,6 +306,24 @@ mod tests {
         assert_eq!(uuid ^ uuid, UUID::nil());
     }
 
+    #[test]
+    fn bitwise_ops_act_bytewise_on_full_width_uuids() {
+        let a = UUID::NS_DNS;
+        let b = UUID::gen_v4();
+
+        let (and, or, xor) = (a & b, a | b, a ^ b);
+
+        for i in 0..16 {
+            assert_eq!(and.as_bytes()[i], a.as_bytes()[i] & b.as_bytes()[i]);
+            assert_eq!(or.as_bytes()[i], a.as_bytes()[i] | b.as_bytes()[i]);
+            assert_eq!(xor.as_bytes()[i], a.as_bytes()[i] ^ b.as_bytes()[i]);
+        }
+
+        assert_eq!(b ^ b, UUID::nil());
+        assert_eq!(b | UUID::max(), UUID::max());
+        assert_eq!(xor ^ b, a, "XOR with a salt must be reversible");
+    }
+
     #[test]
     fn bitxor_with_max() {
         let uuid = UUID::from(0b1010_1010u128);
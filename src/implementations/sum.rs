@@ -0,0 +1,66 @@
+use core::iter::Sum;
+
+use crate::UUID;
+
+/// Summing UUIDs **XOR-folds** them, starting from [`UUID::nil()`].
+///
+/// The result is an order-independent digest of a collection: every element
+/// contributes, and an element present an even number of times cancels out.
+/// This deliberately differs from the wrapping arithmetic of `+`, which
+/// would let carries leak between bit positions, so
+/// `[a, b].into_iter().sum::<UUID>()` is `a ^ b`, not `a + b`.
+///
+/// ```
+/// use ps_uuid::UUID;
+///
+/// let (a, b) = (UUID::gen_v4(), UUID::gen_v4());
+///
+/// assert_eq!([a, b, a].into_iter().sum::<UUID>(), b);
+/// ```
+impl Sum for UUID {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::nil(), |acc, uuid| acc ^ uuid)
+    }
+}
+
+/// XOR-folds borrowed UUIDs; see the owned implementation.
+impl<'a> Sum<&'a Self> for UUID {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::UUID;
+
+    #[test]
+    fn repeated_elements_cancel() {
+        let (a, b) = (UUID::gen_v4(), UUID::gen_v4());
+
+        assert_eq!([a, b, a].into_iter().sum::<UUID>(), b);
+    }
+
+    #[test]
+    fn order_does_not_matter() {
+        let ids: Vec<UUID> = (0..16).map(|_| UUID::gen_v4()).collect();
+        let mut reversed = ids.clone();
+
+        reversed.reverse();
+
+        assert_eq!(ids.iter().sum::<UUID>(), reversed.into_iter().sum::<UUID>());
+    }
+
+    #[test]
+    fn empty_sum_is_nil() {
+        assert_eq!(core::iter::empty::<UUID>().sum::<UUID>(), UUID::nil());
+    }
+
+    #[test]
+    fn sum_is_xor_not_addition() {
+        let one = UUID::from(1u128);
+
+        assert_eq!([one, one].iter().sum::<UUID>(), UUID::nil());
+        assert_eq!(one + one, UUID::from(2u128));
+    }
+}
@@ -1,6 +1,6 @@
 use std::str::FromStr;
 
-use crate::{UuidParseError, UUID, UUID_BYTES};
+use crate::{helpers::uuid_ref, UuidParseError, UUID, UUID_BYTES};
 
 impl TryFrom<&[u8]> for UUID {
     type Error = UuidParseError;
@@ -20,11 +20,53 @@ impl TryFrom<&[u8]> for UUID {
     }
 }
 
+/// Reinterprets a 16-byte slice as a `&UUID` without copying, e.g. to read
+/// a UUID in place from a network buffer.
+///
+/// Unlike `TryFrom<&[u8]> for UUID`, the slice is never parsed as text.
+impl<'a> TryFrom<&'a [u8]> for &'a UUID {
+    type Error = UuidParseError;
+
+    fn try_from(slice: &'a [u8]) -> Result<Self, Self::Error> {
+        let bytes =
+            <&[u8; UUID_BYTES]>::try_from(slice).map_err(|_| UuidParseError::InvalidLength)?;
+
+        Ok(uuid_ref(bytes))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     #![allow(clippy::expect_used)]
     use crate::{UuidParseError, UUID, UUID_BYTES};
 
+    #[test]
+    fn borrowed_uuid_aliases_the_buffer() {
+        let buffer = [0x5A_u8; 20];
+
+        let uuid: &UUID = buffer[2..18].try_into().expect("16 bytes");
+
+        assert_eq!(*uuid, UUID::from_bytes([0x5A; UUID_BYTES]));
+        assert!(core::ptr::eq(
+            uuid.as_bytes().as_ptr(),
+            buffer[2..].as_ptr()
+        ));
+    }
+
+    #[test]
+    fn borrowed_uuid_rejects_other_lengths() {
+        let text = b"6ba7b810-9dad-11d1-80b4-00c04fd430c8";
+
+        assert_eq!(
+            <&UUID>::try_from(&text[..]),
+            Err(UuidParseError::InvalidLength)
+        );
+        assert_eq!(
+            <&UUID>::try_from(&[0u8; 15][..]),
+            Err(UuidParseError::InvalidLength)
+        );
+    }
+
     #[test]
     fn try_from_slice_valid() {
         let bytes: [u8; UUID_BYTES] = [
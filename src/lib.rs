@@ -56,9 +56,17 @@
 //!
 //! # Feature Flags
 //!
-//! - `serde`: Serialization support via Serde
+//! - `serde`: Serialization support via Serde, plus the `serde_u128` module
+//!   for a native 128-bit integer encoding and the `serde_bytes` module for
+//!   raw bytes in every format
 //! - `rkyv`: Zero-copy deserialization via rkyv
+//! - `bytemuck`: `Pod` and `Zeroable` for zero-copy casts via `bytemuck`
+//! - `chrono`: Timestamps as `chrono::DateTime<Utc>`
 //! - `num_traits`: Numeric trait implementations
+//! - `postgres`: `ToSql` and `FromSql` for the `uuid` type of `postgres` and
+//!   `tokio-postgres`
+//! - `rusqlite`: Storage in `SQLite` columns via `rusqlite`
+//! - `time`: Calendar timestamps as `time::OffsetDateTime`
 //! - `uuid-crate-compat`: Interop with the `uuid` crate via `UuidCompat`
 
 mod constants;
@@ -73,14 +81,23 @@ mod node_id;
 mod state;
 mod variant;
 
-pub use error::{DurationToTicksError, UuidConstructionError, UuidParseError};
+pub use error::{
+    DurationToTicksError, NodeIdParseError, UuidConstructionError, UuidParseError,
+    VariantParseError,
+};
 pub use gregorian::Gregorian;
-pub use helpers::{md5, sha1, to_hex, Md5, Sha1, ToHex};
-pub use methods::{Braced, Hyphenated, NcsUuidError, Simple, Urn};
+pub use helpers::{md5, sha1, to_hex, to_hex_upper, Md5, Sha1, ToHex};
+pub use methods::{
+    Braced, Builder, Hyphenated, LeOrdered, NcsAddress, NcsUuidError, PosixUuid, Simple,
+    SqlServerOrdered, Urn, V7Mode, WindowsGuid,
+};
 pub use node_id::{NodeId, NODE_ID_BYTES};
 pub use state::{State, STATE};
 pub use variant::Variant;
 
+#[cfg(feature = "serde")]
+pub use features::{serde_bytes, serde_u128};
+
 #[cfg(feature = "uuid-crate-compat")]
 pub use features::uuid_crate_compat::{Uuid, UuidCompat};
 
@@ -93,3 +110,34 @@ pub const UUID_BYTES: usize = 16;
 pub struct UUID {
     bytes: [u8; UUID_BYTES],
 }
+
+// Every public type must stay `Send + Sync` so it can cross threads and live
+// in async tasks; adding a field that breaks this fails compilation here.
+const _: () = {
+    const fn assert_send_sync<T: Send + Sync>() {}
+
+    assert_send_sync::<UUID>();
+    assert_send_sync::<NodeId>();
+    assert_send_sync::<State>();
+    assert_send_sync::<Variant>();
+    assert_send_sync::<Gregorian>();
+    assert_send_sync::<Builder>();
+    assert_send_sync::<LeOrdered>();
+    assert_send_sync::<NcsAddress>();
+    assert_send_sync::<SqlServerOrdered>();
+    assert_send_sync::<PosixUuid>();
+    assert_send_sync::<WindowsGuid>();
+    assert_send_sync::<Braced>();
+    assert_send_sync::<Hyphenated>();
+    assert_send_sync::<Simple>();
+    assert_send_sync::<Urn>();
+    assert_send_sync::<V7Mode>();
+    assert_send_sync::<Md5>();
+    assert_send_sync::<Sha1>();
+    assert_send_sync::<UuidConstructionError>();
+    assert_send_sync::<DurationToTicksError>();
+    assert_send_sync::<NodeIdParseError>();
+    assert_send_sync::<UuidParseError>();
+    assert_send_sync::<VariantParseError>();
+    assert_send_sync::<NcsUuidError>();
+};
@@ -2,6 +2,9 @@ use crate::UUID;
 
 impl UUID {
     /// Returns a mutable reference to the UUID's bytes.
+    ///
+    /// Writes through this reference may leave the version and variant
+    /// inconsistent; [`UUID::edit`] restores them afterwards.
     #[must_use]
     pub const fn as_mut_bytes(&mut self) -> &mut [u8; 16] {
         &mut self.bytes
@@ -0,0 +1,70 @@
+use crate::UUID;
+
+impl UUID {
+    /// Estimates the expected number of colliding pairs among `n` random
+    /// (v4) UUIDs.
+    ///
+    /// A v4 UUID carries 122 random bits, so each of the `n·(n−1)/2` pairs
+    /// collides with probability 2⁻¹²², and the expectation is their
+    /// product. While the result is well below one it also approximates the
+    /// probability that any collision occurs at all, which is the figure
+    /// capacity planning usually wants.
+    ///
+    /// Inputs below 2 yield `0.0`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ps_uuid::UUID;
+    ///
+    /// // A trillion v4 UUIDs are expected to contain ~1e-13 collisions.
+    /// assert!(UUID::birthday_collision_count(1e12) < 1e-12);
+    /// ```
+    #[must_use]
+    pub fn birthday_collision_count(n: f64) -> f64 {
+        if n >= 2.0 {
+            n * (n - 1.0) / 2.0 / 2f64.powi(122)
+        } else {
+            // Also covers NaN.
+            0.0
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::UUID;
+
+    #[test]
+    fn two_to_the_61_is_on_the_order_of_one() {
+        // (2⁶¹)² / 2 / 2¹²² = 0.5
+        let expected = UUID::birthday_collision_count(2f64.powi(61));
+
+        assert!((expected - 0.5).abs() < 1e-12, "{expected}");
+    }
+
+    #[test]
+    fn grows_quadratically() {
+        let small = UUID::birthday_collision_count(1e15);
+        let large = UUID::birthday_collision_count(1e16);
+
+        assert!((large / small - 100.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn known_approximation() {
+        // 1e12² / 2 / 2¹²² ≈ 9.4e-14
+        let expected = UUID::birthday_collision_count(1e12);
+
+        assert!((9.3e-14..9.5e-14).contains(&expected), "{expected}");
+    }
+
+    #[test]
+    fn fewer_than_two_uuids_cannot_collide() {
+        for n in [0.0, 1.0, 1.5, -3.0, f64::NAN] {
+            assert!(UUID::birthday_collision_count(n) == 0.0, "{n}");
+        }
+
+        assert!(UUID::birthday_collision_count(2.0) > 0.0);
+    }
+}
@@ -0,0 +1,373 @@
+//! Fluent construction of UUIDs.
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use parking_lot::Mutex;
+use rand::random;
+
+use crate::{State, UuidConstructionError, STATE, UUID};
+
+/// A fluent builder over the `new_*` constructors.
+///
+/// Created by calling [`UUID::builder()`]. The version defaults to 7; every
+/// other field is optional:
+///
+/// | Version | `timestamp`    | `clock_seq`    | `node`              |
+/// |---------|----------------|----------------|---------------------|
+/// | 1, 6    | from [`STATE`] | from [`STATE`] | [`STATE`]'s node ID |
+/// | 4       | —              | —              | —                   |
+/// | 7       | from [`STATE`] | —              | —                   |
+///
+/// Supplying a field marked — is rejected with
+/// [`UuidConstructionError::IrrelevantField`] rather than silently ignored.
+///
+/// A version-1 or version-6 UUID that supplies neither `timestamp` nor
+/// `clock_seq` takes both from [`State::next`], and a version-7 UUID without
+/// a `timestamp` takes it from [`State::next_v7`], exactly as the matching
+/// `gen_*` function does, so such UUIDs share its uniqueness and
+/// monotonicity guarantees. Once either field is supplied, [`STATE`] is
+/// bypassed: a missing `timestamp` is the current time and a missing
+/// `clock_seq` is random, and the caller is responsible for uniqueness.
+///
+/// ```
+/// use std::time::{Duration, UNIX_EPOCH};
+///
+/// use ps_uuid::UUID;
+///
+/// let time = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+/// let node = [1, 2, 3, 4, 5, 6];
+///
+/// let built = UUID::builder()
+///     .version(1)
+///     .timestamp(time)
+///     .clock_seq(0x1234)
+///     .node(node)
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(built, UUID::new_v1(time, 0x1234, node).unwrap());
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Builder {
+    version: Option<u8>,
+    timestamp: Option<SystemTime>,
+    clock_seq: Option<u16>,
+    node: Option<[u8; 6]>,
+}
+
+impl Builder {
+    /// Sets the version to build: 1, 4, 6, or 7.
+    #[must_use]
+    pub const fn version(mut self, version: u8) -> Self {
+        self.version = Some(version);
+        self
+    }
+
+    /// Sets the timestamp of a time-based UUID.
+    #[must_use]
+    pub const fn timestamp(mut self, timestamp: SystemTime) -> Self {
+        self.timestamp = Some(timestamp);
+        self
+    }
+
+    /// Sets the 14-bit clock sequence of a version-1 or version-6 UUID; the
+    /// upper two bits are overwritten by the variant.
+    #[must_use]
+    pub const fn clock_seq(mut self, clock_seq: u16) -> Self {
+        self.clock_seq = Some(clock_seq);
+        self
+    }
+
+    /// Sets the 48-bit node ID of a version-1 or version-6 UUID.
+    #[must_use]
+    pub const fn node(mut self, node: [u8; 6]) -> Self {
+        self.node = Some(node);
+        self
+    }
+
+    /// Builds the UUID.
+    ///
+    /// # Errors
+    /// - `UnsupportedVersion` if the version is not 1, 4, 6, or 7.
+    /// - `IrrelevantField` if a field was supplied that the version does not
+    ///   encode.
+    /// - `TimestampBeforeEpoch` or `TimestampOverflow` if the timestamp is
+    ///   outside the range the version can encode.
+    /// - `RateExceeded` if the timestamp and clock sequence are drawn from
+    ///   [`STATE`] and [`State::next`] fails with it.
+    pub fn build(self) -> Result<UUID, UuidConstructionError> {
+        self.build_with(&STATE)
+    }
+
+    /// Builds the UUID, drawing defaults from `state` rather than [`STATE`].
+    fn build_with(self, state: &Mutex<State>) -> Result<UUID, UuidConstructionError> {
+        match self.version.unwrap_or(7) {
+            version @ (1 | 6) => {
+                let (timestamp, clock_seq, node) = match (self.timestamp, self.clock_seq) {
+                    (None, None) => {
+                        let mut guard = state.lock();
+                        let (timestamp, clock_seq) = guard.next(SystemTime::now())?;
+                        let node = self.node.unwrap_or_else(|| guard.node_id().bytes);
+
+                        drop(guard);
+
+                        (timestamp, clock_seq, node)
+                    }
+                    (timestamp, clock_seq) => (
+                        timestamp.unwrap_or_else(SystemTime::now),
+                        clock_seq.unwrap_or_else(random),
+                        self.node.unwrap_or_else(|| state.lock().node_id().bytes),
+                    ),
+                };
+
+                if version == 1 {
+                    UUID::new_v1(timestamp, clock_seq, node)
+                } else {
+                    UUID::new_v6(timestamp, clock_seq, node)
+                }
+            }
+            4 => {
+                self.reject_timestamp()?;
+                self.reject_clock_seq_and_node()?;
+
+                Ok(UUID::gen_v4())
+            }
+            7 => {
+                self.reject_clock_seq_and_node()?;
+
+                let since_unix = self
+                    .timestamp
+                    .unwrap_or_else(|| state.lock().next_v7(SystemTime::now()))
+                    .duration_since(UNIX_EPOCH)
+                    .map_err(|_| UuidConstructionError::TimestampBeforeEpoch)?;
+
+                UUID::new_v7_checked(since_unix, random())
+            }
+            version => Err(UuidConstructionError::UnsupportedVersion(version)),
+        }
+    }
+
+    const fn reject_timestamp(&self) -> Result<(), UuidConstructionError> {
+        if self.timestamp.is_some() {
+            return Err(UuidConstructionError::IrrelevantField("timestamp"));
+        }
+
+        Ok(())
+    }
+
+    const fn reject_clock_seq_and_node(&self) -> Result<(), UuidConstructionError> {
+        if self.clock_seq.is_some() {
+            return Err(UuidConstructionError::IrrelevantField("clock_seq"));
+        }
+
+        if self.node.is_some() {
+            return Err(UuidConstructionError::IrrelevantField("node"));
+        }
+
+        Ok(())
+    }
+}
+
+impl UUID {
+    /// Returns a [`Builder`] for constructing a UUID field by field.
+    #[must_use]
+    pub const fn builder() -> Builder {
+        Builder {
+            version: None,
+            timestamp: None,
+            clock_seq: None,
+            node: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    use parking_lot::Mutex;
+
+    use crate::{State, UuidConstructionError, Variant, UUID};
+
+    const NODE: [u8; 6] = [0xAA, 0xBB, 0xCC, 0xDD, 0xEE, 0xFF];
+
+    fn time() -> SystemTime {
+        UNIX_EPOCH + Duration::from_secs(1_700_000_000)
+    }
+
+    #[test]
+    fn v1_matches_new_v1() {
+        let built = UUID::builder()
+            .version(1)
+            .timestamp(time())
+            .clock_seq(0x2A3B)
+            .node(NODE)
+            .build()
+            .expect("build must succeed");
+
+        assert_eq!(
+            built,
+            UUID::new_v1(time(), 0x2A3B, NODE).expect("new_v1 must succeed")
+        );
+    }
+
+    #[test]
+    fn v6_matches_new_v6() {
+        let built = UUID::builder()
+            .version(6)
+            .timestamp(time())
+            .clock_seq(0x2A3B)
+            .node(NODE)
+            .build()
+            .expect("build must succeed");
+
+        assert_eq!(
+            built,
+            UUID::new_v6(time(), 0x2A3B, NODE).expect("new_v6 must succeed")
+        );
+    }
+
+    #[test]
+    fn v1_defaults_to_the_state_node() {
+        // A private state, so concurrent resets of `STATE` cannot interfere.
+        let state = Mutex::new(State::default());
+        let node = state.lock().node_id();
+
+        let built = UUID::builder()
+            .version(1)
+            .build_with(&state)
+            .expect("build must succeed");
+
+        assert_eq!(built.get_version(), Some(1));
+        assert_eq!(
+            built.get_node_id(),
+            Some(node),
+            "The default node must be the state's node ID."
+        );
+    }
+
+    #[test]
+    fn default_v1_and_v6_draw_from_the_state_sequence() {
+        let state = Mutex::new(State::default());
+        let mut seen = std::collections::HashSet::new();
+
+        for version in [1, 6, 1, 6] {
+            let built = UUID::builder()
+                .version(version)
+                .build_with(&state)
+                .expect("build must succeed");
+
+            // The state issued exactly the built tick.
+            let issued = UUID::new_v1(state.lock().last_ts(), 0, [0; 6])
+                .expect("the issued tick is representable");
+
+            assert_eq!(built.get_timestamp(), issued.get_timestamp());
+            assert!(seen.insert(built.logical_key()), "{built} repeats a tick");
+        }
+    }
+
+    #[test]
+    fn default_v7_draws_from_the_state_sequence() {
+        let state = Mutex::new(State::default());
+        let mut previous = UUID::nil();
+
+        for _ in 0..1_000 {
+            let built = UUID::builder()
+                .build_with(&state)
+                .expect("build must succeed");
+
+            assert!(built > previous, "{built} must follow {previous}");
+            assert_eq!(
+                built.get_timestamp().map(|ts| ts <= state.lock().last_ts()),
+                Some(true)
+            );
+
+            previous = built;
+        }
+    }
+
+    #[test]
+    fn v4_is_random() {
+        let built = UUID::builder()
+            .version(4)
+            .build()
+            .expect("build must succeed");
+
+        assert_eq!(built.get_version(), Some(4));
+        assert_eq!(built.get_variant(), Variant::OSF);
+        assert_ne!(
+            built,
+            UUID::builder()
+                .version(4)
+                .build()
+                .expect("build must succeed")
+        );
+    }
+
+    #[test]
+    fn v7_matches_new_v7_timestamp() {
+        let built = UUID::builder()
+            .version(7)
+            .timestamp(time())
+            .build()
+            .expect("build must succeed");
+        let direct = UUID::new_v7(
+            time().duration_since(UNIX_EPOCH).expect("after the epoch"),
+            [0; 8],
+        );
+
+        assert_eq!(built.get_version(), Some(7));
+        assert_eq!(built.as_bytes()[..8], direct.as_bytes()[..8]);
+        assert_eq!(built.get_timestamp(), Some(time()));
+    }
+
+    #[test]
+    fn version_defaults_to_7() {
+        let built = UUID::builder().build().expect("build must succeed");
+
+        assert_eq!(built.get_version(), Some(7));
+    }
+
+    #[test]
+    fn irrelevant_fields_are_rejected() {
+        assert_eq!(
+            UUID::builder().version(4).timestamp(time()).build(),
+            Err(UuidConstructionError::IrrelevantField("timestamp"))
+        );
+        assert_eq!(
+            UUID::builder().version(4).node(NODE).build(),
+            Err(UuidConstructionError::IrrelevantField("node"))
+        );
+        assert_eq!(
+            UUID::builder().version(7).clock_seq(1).build(),
+            Err(UuidConstructionError::IrrelevantField("clock_seq"))
+        );
+    }
+
+    #[test]
+    fn unsupported_versions_are_rejected() {
+        for version in [0, 2, 3, 5, 8, 16] {
+            assert_eq!(
+                UUID::builder().version(version).build(),
+                Err(UuidConstructionError::UnsupportedVersion(version))
+            );
+        }
+    }
+
+    #[test]
+    fn v7_timestamp_range_is_checked() {
+        assert_eq!(
+            UUID::builder()
+                .timestamp(UNIX_EPOCH - Duration::from_secs(1))
+                .build(),
+            Err(UuidConstructionError::TimestampBeforeEpoch)
+        );
+        assert_eq!(
+            UUID::builder()
+                .timestamp(UNIX_EPOCH + Duration::from_millis(1 << 48))
+                .build(),
+            Err(UuidConstructionError::TimestampOverflow)
+        );
+    }
+}
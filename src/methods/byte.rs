@@ -0,0 +1,47 @@
+use crate::UUID;
+
+impl UUID {
+    /// Returns the byte at position `i`, counting from the most significant
+    /// byte (the first two hex digits of the string form) as 0.
+    ///
+    /// The variant occupies the top bits of `byte(8)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i >= 16`; in a const context this is a compile-time error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ps_uuid::UUID;
+    ///
+    /// const BYTE_8: u8 = UUID::NS_DNS.byte(8);
+    ///
+    /// assert_eq!(BYTE_8, 0x80);
+    /// assert_eq!(UUID::gen_v4().byte(8) >> 6, 0b10);
+    /// ```
+    #[must_use]
+    pub const fn byte(&self, i: usize) -> u8 {
+        self.bytes[i]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::UUID;
+
+    #[test]
+    fn matches_as_bytes() {
+        let uuid = UUID::gen_v4();
+
+        for i in 0..16 {
+            assert_eq!(uuid.byte(i), uuid.as_bytes()[i]);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn out_of_range_panics() {
+        let _ = UUID::nil().byte(16);
+    }
+}
@@ -0,0 +1,58 @@
+use crate::{UuidParseError, UUID};
+
+impl UUID {
+    /// Normalizes any spelling [`FromStr`](std::str::FromStr) accepts into
+    /// the lowercase hyphenated canonical form.
+    ///
+    /// Storing only canonical strings keeps one UUID from appearing as
+    /// several rows that differ only in case, braces, or a `urn:uuid:`
+    /// prefix.
+    ///
+    /// # Errors
+    ///
+    /// Returns the [`UuidParseError`] that parsing `s` produced.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ps_uuid::UUID;
+    ///
+    /// assert_eq!(
+    ///     UUID::canonicalize("{6BA7B810-9DAD-11D1-80B4-00C04FD430C8}").unwrap(),
+    ///     "6ba7b810-9dad-11d1-80b4-00c04fd430c8"
+    /// );
+    /// ```
+    pub fn canonicalize(s: &str) -> Result<String, UuidParseError> {
+        Ok(s.parse::<Self>()?.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{UuidParseError, UUID};
+
+    const CANONICAL: &str = "6ba7b810-9dad-11d1-80b4-00c04fd430c8";
+
+    #[test]
+    fn every_spelling_canonicalizes_identically() {
+        for s in [
+            CANONICAL,
+            "6BA7B810-9DAD-11D1-80B4-00C04FD430C8",
+            "6ba7b8109dad11d180b400c04fd430c8",
+            "{6ba7b810-9dad-11d1-80b4-00c04fd430c8}",
+            "{6BA7B810-9DAD-11D1-80B4-00C04FD430C8}",
+            "urn:uuid:6ba7b810-9dad-11d1-80b4-00c04fd430c8",
+            "URN:UUID:6BA7B810-9DAD-11D1-80B4-00C04FD430C8",
+        ] {
+            assert_eq!(UUID::canonicalize(s).as_deref(), Ok(CANONICAL), "{s}");
+        }
+    }
+
+    #[test]
+    fn invalid_input_is_rejected() {
+        assert_eq!(
+            UUID::canonicalize("not-a-uuid"),
+            Err(UuidParseError::InvalidLength)
+        );
+    }
+}
@@ -0,0 +1,67 @@
+use crate::UUID;
+
+impl UUID {
+    /// Adds `n` to the UUID's big-endian 128-bit value, returning `None`
+    /// instead of wrapping past [`UUID::max`].
+    ///
+    /// Unlike the wrapping `+` operator, this lets a range allocator detect
+    /// that a block of `n` consecutive IDs does not fit above `self`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ps_uuid::UUID;
+    ///
+    /// assert_eq!(UUID::nil().checked_add(1), Some(UUID::from_u128(1)));
+    /// assert_eq!(UUID::max().checked_add(1), None);
+    /// ```
+    #[must_use]
+    pub const fn checked_add(self, n: u128) -> Option<Self> {
+        match self.to_u128().checked_add(n) {
+            Some(value) => Some(Self::from_u128(value)),
+            None => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::UUID;
+
+    #[test]
+    fn adds_to_the_big_endian_value() {
+        let uuid = UUID::from_u128(0x00FF);
+
+        assert_eq!(uuid.checked_add(1), Some(UUID::from_u128(0x0100)));
+        assert_eq!(uuid.checked_add(0), Some(uuid));
+    }
+
+    #[test]
+    fn reaches_max_exactly() {
+        assert_eq!(UUID::nil().checked_add(u128::MAX), Some(UUID::max()));
+        assert_eq!(
+            UUID::from_u128(u128::MAX - 5).checked_add(5),
+            Some(UUID::max())
+        );
+    }
+
+    #[test]
+    fn overflow_returns_none() {
+        assert_eq!(UUID::max().checked_add(1), None);
+        assert_eq!(UUID::from_u128(1).checked_add(u128::MAX), None);
+    }
+
+    #[test]
+    fn agrees_with_wrapping_add_when_in_range() {
+        let uuid = UUID::NS_DNS;
+
+        assert_eq!(uuid.checked_add(12_345), Some(uuid + 12_345u128));
+    }
+
+    #[test]
+    fn const_context() {
+        const NEXT: Option<UUID> = UUID::nil().checked_add(1);
+
+        assert_eq!(NEXT, Some(UUID::from_u128(1)));
+    }
+}
@@ -0,0 +1,68 @@
+use crate::UUID;
+
+impl UUID {
+    /// Subtracts `n` from the UUID's big-endian 128-bit value, returning
+    /// `None` instead of wrapping below [`UUID::nil`].
+    ///
+    /// This is the counterpart of [`UUID::checked_add`]; the `-` operator
+    /// wraps instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ps_uuid::UUID;
+    ///
+    /// assert_eq!(UUID::max().checked_sub(u128::MAX), Some(UUID::nil()));
+    /// assert_eq!(UUID::nil().checked_sub(1), None);
+    /// ```
+    #[must_use]
+    pub const fn checked_sub(self, n: u128) -> Option<Self> {
+        match self.to_u128().checked_sub(n) {
+            Some(value) => Some(Self::from_u128(value)),
+            None => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::UUID;
+
+    #[test]
+    fn subtracts_from_the_big_endian_value() {
+        let uuid = UUID::from_u128(0x0100);
+
+        assert_eq!(uuid.checked_sub(1), Some(UUID::from_u128(0x00FF)));
+        assert_eq!(uuid.checked_sub(0), Some(uuid));
+    }
+
+    #[test]
+    fn reaches_nil_exactly() {
+        assert_eq!(UUID::max().checked_sub(u128::MAX), Some(UUID::nil()));
+        assert_eq!(UUID::from_u128(5).checked_sub(5), Some(UUID::nil()));
+    }
+
+    #[test]
+    fn underflow_returns_none() {
+        assert_eq!(UUID::nil().checked_sub(1), None);
+        assert_eq!(UUID::from_u128(u128::MAX - 1).checked_sub(u128::MAX), None);
+    }
+
+    #[test]
+    fn inverts_checked_add() {
+        let uuid = UUID::NS_URL;
+
+        assert_eq!(
+            uuid.checked_add(1 << 100)
+                .and_then(|u| u.checked_sub(1 << 100)),
+            Some(uuid)
+        );
+    }
+
+    #[test]
+    fn const_context() {
+        const PREV: Option<UUID> = UUID::max().checked_sub(1);
+
+        assert_eq!(PREV, Some(UUID::from_u128(u128::MAX - 1)));
+    }
+}
@@ -0,0 +1,46 @@
+use crate::UUID;
+
+impl UUID {
+    /// Returns `true` if both UUIDs hold the same 16 bytes.
+    ///
+    /// This is `==` for `const` contexts, where [`PartialEq`] cannot be
+    /// called, e.g. in a compile-time assertion:
+    ///
+    /// ```
+    /// use ps_uuid::UUID;
+    ///
+    /// const _: () = assert!(UUID::NIL.const_eq(&UUID::nil()));
+    /// ```
+    #[must_use]
+    pub const fn const_eq(&self, other: &Self) -> bool {
+        self.to_u128() == other.to_u128()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::UUID;
+
+    const _: () = assert!(UUID::NIL.const_eq(&UUID::nil()));
+    const _: () = assert!(!UUID::NS_DNS.const_eq(&UUID::NS_URL));
+
+    #[test]
+    fn evaluates_at_compile_time() {
+        const RESULTS: [bool; 2] = [
+            UUID::MAX.const_eq(&UUID::max()),
+            UUID::nil().const_eq(&UUID::max()),
+        ];
+
+        assert_eq!(RESULTS, [true, false]);
+    }
+
+    #[test]
+    fn agrees_with_partial_eq() {
+        let a = UUID::gen_v4();
+        let b = UUID::gen_v4();
+
+        assert!(a.const_eq(&a));
+        assert_eq!(a.const_eq(&b), a == b);
+        assert!(!a.const_eq(&(a ^ UUID::from_u128(1))));
+    }
+}
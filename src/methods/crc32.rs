@@ -0,0 +1,97 @@
+use crate::UUID;
+
+/// The reflected CRC-32 (IEEE 802.3) polynomial.
+const POLYNOMIAL: u32 = 0xEDB8_8320;
+
+/// The byte-at-a-time lookup table, computed at compile time.
+const TABLE: [u32; 256] = {
+    let mut table = [0; 256];
+    let mut i: u32 = 0;
+
+    while i < 256 {
+        let mut crc = i;
+        let mut bit = 0;
+
+        while bit < 8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ POLYNOMIAL
+            } else {
+                crc >> 1
+            };
+            bit += 1;
+        }
+
+        table[i as usize] = crc;
+        i += 1;
+    }
+
+    table
+};
+
+impl UUID {
+    /// Computes the CRC-32 (IEEE, as used by zlib, gzip and PNG) of the 16
+    /// bytes in network order.
+    ///
+    /// This detects accidental corruption, e.g. by appending the 4-byte
+    /// checksum to a UUID on error-prone storage; it offers no protection
+    /// against deliberate tampering.
+    ///
+    /// # Example
+    /// ```
+    /// use ps_uuid::UUID;
+    ///
+    /// assert_eq!(UUID::NS_DNS.crc32(), 0x130A_5BBF);
+    /// ```
+    #[must_use]
+    pub const fn crc32(&self) -> u32 {
+        let mut crc = u32::MAX;
+        let mut i = 0;
+
+        while i < self.bytes.len() {
+            crc = (crc >> 8) ^ TABLE[((crc ^ self.bytes[i] as u32) & 0xFF) as usize];
+            i += 1;
+        }
+
+        !crc
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+    use super::{POLYNOMIAL, TABLE};
+    use crate::UUID;
+
+    #[test]
+    fn pins_known_checksums() {
+        assert_eq!(UUID::nil().crc32(), 0xECBB_4B55);
+        assert_eq!(UUID::NS_DNS.crc32(), 0x130A_5BBF);
+
+        // The version-4 example from RFC 9562, Appendix A.3.
+        let rfc_sample: UUID = "919108f7-52d1-4320-9bac-f847db4148a8"
+            .parse()
+            .expect("valid UUID");
+
+        assert_eq!(rfc_sample.crc32(), 0x771D_088F);
+    }
+
+    #[test]
+    fn table_matches_the_standard_one() {
+        assert_eq!(TABLE[0], 0);
+        assert_eq!(TABLE[1], 0x7707_3096);
+        assert_eq!(TABLE[128], POLYNOMIAL);
+        assert_eq!(TABLE[255], 0x2D02_EF8D);
+    }
+
+    #[test]
+    fn detects_single_bit_flips() {
+        let uuid = UUID::gen_v4();
+        let crc = uuid.crc32();
+
+        for bit in 0..128 {
+            let flipped = UUID::from_u128(uuid.to_u128() ^ (1 << bit));
+
+            assert_ne!(flipped.crc32(), crc, "bit {bit}");
+        }
+    }
+}
@@ -0,0 +1,55 @@
+use std::hint::black_box;
+
+use crate::UUID;
+
+impl UUID {
+    /// Returns `true` if this is the nil UUID, taking the same time whatever
+    /// the UUID's contents.
+    ///
+    /// [`UUID::is_nil`] may stop at the first non-zero byte, so comparing a
+    /// secret token against the nil "unset" sentinel with it leaks how many
+    /// leading bytes are zero. This variant ORs all 16 bytes together
+    /// without an early exit and only then tests the result; the bytes are
+    /// passed through [`black_box`] to keep the optimizer from reintroducing
+    /// a short circuit.
+    #[must_use]
+    pub fn ct_is_nil(&self) -> bool {
+        let acc = black_box(self.bytes)
+            .iter()
+            .fold(0u8, |acc, &byte| acc | byte);
+
+        black_box(acc) == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::UUID;
+
+    #[test]
+    fn nil_is_nil() {
+        assert!(UUID::nil().ct_is_nil());
+    }
+
+    #[test]
+    fn agrees_with_is_nil() {
+        for _ in 0..1000 {
+            let uuid = UUID::gen_v4();
+
+            assert_eq!(uuid.ct_is_nil(), uuid.is_nil());
+        }
+
+        for uuid in [UUID::max(), UUID::NS_DNS, UUID::from_u128(1)] {
+            assert_eq!(uuid.ct_is_nil(), uuid.is_nil());
+        }
+    }
+
+    #[test]
+    fn any_single_set_bit_is_detected() {
+        for bit in 0..128 {
+            let uuid = UUID::from_u128(1 << bit);
+
+            assert!(!uuid.ct_is_nil(), "bit {bit}");
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use crate::{Variant, UUID};
+
+impl UUID {
+    /// Decodes the fields of a DCOM variant UUID, the inverse of
+    /// [`UUID::from_parts_dcom`].
+    ///
+    /// Returns `(time_low, time_mid, time_hi, clock_seq, node)`, reading the
+    /// three time fields little-endian and the clock sequence big-endian, as
+    /// the constructor lays them out. The variant occupies the top three bits
+    /// of the clock sequence, so only its low 13 bits are returned.
+    ///
+    /// Returns `None` if the UUID is not of the [`Variant::DCOM`] variant.
+    #[must_use]
+    pub const fn dcom_fields(&self) -> Option<(u32, u16, u16, u16, [u8; 6])> {
+        if !matches!(self.get_variant(), Variant::DCOM) {
+            return None;
+        }
+
+        let [t0, t1, t2, t3, m0, m1, h0, h1, c0, c1, n0, n1, n2, n3, n4, n5] = self.bytes;
+
+        Some((
+            u32::from_le_bytes([t0, t1, t2, t3]),
+            u16::from_le_bytes([m0, m1]),
+            u16::from_le_bytes([h0, h1]),
+            u16::from_be_bytes([c0, c1]) & 0x1FFF,
+            [n0, n1, n2, n3, n4, n5],
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use crate::UUID;
+
+    #[test]
+    fn round_trips_from_parts_dcom() {
+        let fields = (
+            0x1234_5678,
+            0x9ABC,
+            0xDEF0,
+            0x1234,
+            [0xAA, 0xBB, 0xCC, 0xDD, 0xEE, 0xFF],
+        );
+
+        let uuid = UUID::from_parts_dcom(fields.0, fields.1, fields.2, fields.3, fields.4);
+
+        assert_eq!(uuid.dcom_fields(), Some(fields));
+    }
+
+    #[test]
+    fn clock_seq_is_truncated_to_13_bits() {
+        let uuid = UUID::from_parts_dcom(0, 0, 0, 0xFFFF, [0; 6]);
+
+        let (_, _, _, clock_seq, _) = uuid.dcom_fields().expect("must be DCOM");
+
+        assert_eq!(clock_seq, 0x1FFF);
+    }
+
+    #[test]
+    fn agrees_with_other_accessors() {
+        let time = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        let uuid = UUID::new_dcom(time, 0x0ACE, [1, 2, 3, 4, 5, 6]).expect("must construct");
+
+        let (_, _, _, clock_seq, node) = uuid.dcom_fields().expect("must be DCOM");
+
+        assert_eq!(Some(clock_seq), uuid.get_clock_seq());
+        assert_eq!(uuid.get_node_id().map(|n| n.bytes), Some(node));
+    }
+
+    #[test]
+    fn non_dcom_yields_none() {
+        assert_eq!(UUID::nil().dcom_fields(), None);
+        assert_eq!(UUID::gen_v4().dcom_fields(), None);
+        assert_eq!(UUID::max().dcom_fields(), None);
+    }
+}
@@ -0,0 +1,160 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::UUID;
+
+/// Converts days since 1970-01-01 into a proleptic Gregorian
+/// `(year, month, day)`, after Howard Hinnant's `civil_from_days`.
+const fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    (year, month, day)
+}
+
+/// Formats `time` as an ISO-8601 UTC timestamp with nanosecond precision,
+/// e.g. `1998-02-04T22:13:53.151182400Z`.
+fn iso_8601(time: SystemTime) -> String {
+    let (secs, nanos) = match time.duration_since(UNIX_EPOCH) {
+        Ok(since) => (i128::from(since.as_secs()), since.subsec_nanos()),
+        Err(err) => {
+            let until = err.duration();
+            let secs = -i128::from(until.as_secs());
+
+            match until.subsec_nanos() {
+                0 => (secs, 0),
+                nanos => (secs - 1, 1_000_000_000 - nanos),
+            }
+        }
+    };
+
+    // `SystemTime` spans at most ±2⁶³ seconds, so the day count fits.
+    #[allow(clippy::cast_possible_truncation)]
+    let (year, month, day) = civil_from_days(secs.div_euclid(86_400) as i64);
+    let second_of_day = secs.rem_euclid(86_400);
+
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}.{nanos:09}Z",
+        second_of_day / 3_600,
+        second_of_day / 60 % 60,
+        second_of_day % 60,
+    )
+}
+
+impl UUID {
+    /// Returns a multi-line, human-readable report of everything the UUID
+    /// encodes, for diagnostic tooling.
+    ///
+    /// One `key: value` line is emitted for each of the canonical string,
+    /// the [version name](UUID::version_name) and the variant, followed by
+    /// the decoded timestamp in ISO-8601 UTC, the node ID and the clock
+    /// sequence for the UUIDs that carry them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ps_uuid::UUID;
+    ///
+    /// assert_eq!(
+    ///     UUID::NS_DNS.debug_report(),
+    ///     "uuid: 6ba7b810-9dad-11d1-80b4-00c04fd430c8\n\
+    ///      version: time (v1)\n\
+    ///      variant: OSF\n\
+    ///      timestamp: 1998-02-04T22:13:53.151182400Z\n\
+    ///      node: 00:c0:4f:d4:30:c8\n\
+    ///      clock_seq: 0x00b4"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn debug_report(&self) -> String {
+        let mut lines = vec![
+            format!("uuid: {self}"),
+            format!("version: {}", self.version_name()),
+            format!("variant: {}", self.get_variant()),
+        ];
+
+        if let Some(timestamp) = self.get_timestamp() {
+            lines.push(format!("timestamp: {}", iso_8601(timestamp)));
+        }
+
+        if let Some(node) = self.get_node_id() {
+            let [n0, n1, n2, n3, n4, n5] = node.bytes;
+
+            lines.push(format!(
+                "node: {n0:02x}:{n1:02x}:{n2:02x}:{n3:02x}:{n4:02x}:{n5:02x}"
+            ));
+        }
+
+        if let Some(clock_seq) = self.get_clock_seq() {
+            lines.push(format!("clock_seq: {clock_seq:#06x}"));
+        }
+
+        lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use super::iso_8601;
+    use crate::UUID;
+
+    #[test]
+    fn v1_report_names_the_version_and_timestamp() {
+        let report = UUID::gen_v1()
+            .expect("generation must succeed")
+            .debug_report();
+
+        assert!(report.contains("version: time (v1)"), "{report}");
+        assert!(
+            report
+                .lines()
+                .any(|line| line.starts_with("timestamp: ") && line.ends_with('Z')),
+            "{report}"
+        );
+        assert!(report.lines().any(|line| line.starts_with("node: ")));
+        assert!(report.lines().any(|line| line.starts_with("clock_seq: ")));
+    }
+
+    #[test]
+    fn absent_fields_are_omitted() {
+        let uuid = UUID::gen_v4();
+
+        assert_eq!(
+            uuid.debug_report(),
+            format!("uuid: {uuid}\nversion: random (v4)\nvariant: OSF")
+        );
+    }
+
+    #[test]
+    fn formats_timestamps_around_the_epoch() {
+        assert_eq!(iso_8601(UNIX_EPOCH), "1970-01-01T00:00:00.000000000Z");
+        assert_eq!(
+            iso_8601(UNIX_EPOCH + Duration::new(951_782_400, 5)),
+            "2000-02-29T00:00:00.000000005Z"
+        );
+        assert_eq!(
+            iso_8601(UNIX_EPOCH - Duration::from_nanos(1)),
+            "1969-12-31T23:59:59.999999999Z"
+        );
+    }
+
+    // The instant precedes 1601-01-01, which only platforms with a signed
+    // clock representation can express.
+    #[cfg(unix)]
+    #[test]
+    fn formats_the_gregorian_epoch() {
+        assert_eq!(
+            iso_8601(crate::Gregorian::epoch()),
+            "1582-10-15T00:00:00.000000000Z"
+        );
+    }
+}
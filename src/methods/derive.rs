@@ -0,0 +1,67 @@
+use crate::UUID;
+
+impl UUID {
+    /// Derives a deterministic child UUID from this UUID and `salt`.
+    ///
+    /// This is exactly a version-5 UUID with `self` as the namespace and
+    /// `salt` as the name, i.e. `SHA-1(self.bytes || salt)` truncated to 16
+    /// bytes with the version and variant bits set, so
+    /// `parent.derive(salt) == UUID::new_v5(&parent, salt)`. The same parent
+    /// and salt always yield the same child, which makes it suitable for
+    /// content-derived sub-keys.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ps_uuid::UUID;
+    ///
+    /// let parent = UUID::gen_v4();
+    ///
+    /// assert_eq!(parent.derive(b"avatar"), parent.derive(b"avatar"));
+    /// assert_ne!(parent.derive(b"avatar"), parent.derive(b"banner"));
+    /// ```
+    #[must_use]
+    pub fn derive<S>(&self, salt: S) -> Self
+    where
+        S: AsRef<[u8]>,
+    {
+        Self::new_v5(self, salt)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{sha1, UUID};
+
+    #[test]
+    fn derivation_is_deterministic() {
+        let parent = UUID::gen_v4();
+
+        assert_eq!(parent.derive(b"child"), parent.derive(b"child"));
+    }
+
+    #[test]
+    fn different_salts_and_parents_diverge() {
+        let parent = UUID::gen_v4();
+
+        assert_ne!(parent.derive(b"a"), parent.derive(b"b"));
+        assert_ne!(parent.derive(b""), parent.derive(b"a"));
+        assert_ne!(parent.derive(b"a"), UUID::gen_v4().derive(b"a"));
+    }
+
+    #[test]
+    fn is_sha1_of_parent_and_salt_as_v5() {
+        let parent = UUID::NS_DNS;
+        let child = parent.derive("python.org");
+
+        let mut input = parent.as_bytes().to_vec();
+
+        input.extend_from_slice(b"python.org");
+
+        let digest = sha1(&input);
+
+        assert_eq!(child.as_bytes()[..6], digest[..6]);
+        assert_eq!(child.get_version(), Some(5));
+        assert_eq!(child.to_string(), "886313e1-3b8a-5372-9b90-0c9aee199e5d");
+    }
+}
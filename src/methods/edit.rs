@@ -0,0 +1,56 @@
+use crate::UUID;
+
+impl UUID {
+    /// Runs `f` on the UUID's bytes, then, if `fix_version` is `Some(v)`,
+    /// re-applies version `v` and the [`Variant::OSF`](crate::Variant::OSF)
+    /// variant as [`UUID::set_version`] does.
+    ///
+    /// Unlike [`UUID::as_mut_bytes`], this cannot leave a versioned UUID
+    /// malformed: whatever `f` writes to the version nibble or variant bits
+    /// is overwritten afterwards.
+    pub fn edit<F: FnOnce(&mut [u8; 16])>(&mut self, f: F, fix_version: Option<u8>) {
+        f(&mut self.bytes);
+
+        if let Some(version) = fix_version {
+            self.set_version(version);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+    use crate::{Variant, UUID};
+
+    #[test]
+    fn editing_timestamp_bytes_keeps_version() {
+        let mut uuid = UUID::gen_v1().expect("generation must succeed");
+
+        uuid.edit(|bytes| bytes[0..8].fill(0xFF), Some(1));
+
+        assert_eq!(uuid.get_version(), Some(1));
+        assert_eq!(uuid.get_variant(), Variant::OSF);
+        assert_eq!(&uuid.as_bytes()[0..6], &[0xFF; 6]);
+        assert_eq!(uuid.as_bytes()[6], 0x1F);
+        assert_eq!(uuid.as_bytes()[7], 0xFF);
+    }
+
+    #[test]
+    fn variant_bits_are_restored() {
+        let mut uuid = UUID::gen_v4();
+
+        uuid.edit(|bytes| bytes[8] = 0xFF, Some(4));
+
+        assert_eq!(uuid.get_version(), Some(4));
+        assert_eq!(uuid.as_bytes()[8], 0xBF);
+    }
+
+    #[test]
+    fn without_fix_the_edit_is_raw() {
+        let mut uuid = UUID::gen_v4();
+
+        uuid.edit(|bytes| *bytes = [0; 16], None);
+
+        assert_eq!(uuid, UUID::nil());
+    }
+}
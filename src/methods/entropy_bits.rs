@@ -0,0 +1,71 @@
+use crate::UUID;
+
+impl UUID {
+    /// Estimates how many bits of this UUID are unguessable, judging by its
+    /// version alone.
+    ///
+    /// | Version            | Bits | Source                                       |
+    /// |--------------------|------|----------------------------------------------|
+    /// | 4                  | 122  | every bit except version and variant         |
+    /// | 7                  | 74   | `rand_a` (12) and `rand_b` (62)              |
+    /// | 1, 6               | 14   | the clock sequence, if randomly initialized  |
+    /// | 2                  | 6    | the truncated clock sequence                 |
+    /// | 3, 5               | 0    | derived from the namespace and name          |
+    /// | 8, other variants  | 0    | layout unknown, so nothing is assumed random |
+    ///
+    /// The estimate counts the bits the layout designates as random, so it
+    /// is an upper bound: [`UUID::gen_v7`] fills `rand_a` with a
+    /// sub-millisecond fraction, leaving 62 unguessable bits, and time-based
+    /// UUIDs reveal their node ID and timestamp to anyone who has seen
+    /// another UUID from the same generator. Only a v4 UUID from a CSPRNG is
+    /// suitable as a capability token.
+    #[must_use]
+    pub const fn entropy_bits(&self) -> u8 {
+        match self.get_version() {
+            Some(4) => 122,
+            Some(7) => 74,
+            Some(1 | 6) => 14,
+            Some(2) => 6,
+            _ => 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+    use crate::UUID;
+
+    #[test]
+    fn v4_has_122_bits() {
+        assert_eq!(UUID::gen_v4().entropy_bits(), 122);
+    }
+
+    #[test]
+    fn v7_has_74_bits() {
+        let uuid = UUID::gen_v7().expect("generation must succeed");
+
+        assert_eq!(uuid.entropy_bits(), 74);
+    }
+
+    #[test]
+    fn time_based_versions_have_little() {
+        let v1 = UUID::gen_v1().expect("generation must succeed");
+        let v6 = UUID::gen_v6().expect("generation must succeed");
+
+        assert_eq!(v1.entropy_bits(), 14);
+        assert_eq!(v6.entropy_bits(), 14);
+    }
+
+    #[test]
+    fn name_based_versions_have_none() {
+        assert_eq!(UUID::new_v3(&UUID::NS_DNS, b"example").entropy_bits(), 0);
+        assert_eq!(UUID::new_v5(&UUID::NS_DNS, b"example").entropy_bits(), 0);
+    }
+
+    #[test]
+    fn nil_and_max_have_none() {
+        assert_eq!(UUID::nil().entropy_bits(), 0);
+        assert_eq!(UUID::max().entropy_bits(), 0);
+    }
+}
@@ -0,0 +1,58 @@
+use crate::UUID;
+
+impl UUID {
+    /// Returns `true` if `s` parses to this UUID.
+    ///
+    /// Any spelling [`FromStr`](std::str::FromStr) accepts matches, in
+    /// either case, and malformed input simply compares unequal. Parsing
+    /// does not allocate, so this is cheaper than comparing against
+    /// `self.to_string()`, which also only matches one lowercase spelling.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ps_uuid::UUID;
+    ///
+    /// assert!(UUID::NS_DNS.eq_str("6BA7B810-9DAD-11D1-80B4-00C04FD430C8"));
+    /// assert!(!UUID::NS_DNS.eq_str("not-a-uuid"));
+    /// ```
+    #[must_use]
+    pub fn eq_str(&self, s: &str) -> bool {
+        s.parse::<Self>().is_ok_and(|parsed| parsed == *self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::UUID;
+
+    #[test]
+    fn equal_spellings_match() {
+        for s in [
+            "6ba7b810-9dad-11d1-80b4-00c04fd430c8",
+            "6BA7B810-9DAD-11D1-80B4-00C04FD430C8",
+            "6ba7b8109dad11d180b400c04fd430c8",
+            "{6ba7b810-9dad-11d1-80b4-00c04fd430c8}",
+            "urn:uuid:6ba7b810-9dad-11d1-80b4-00c04fd430c8",
+        ] {
+            assert!(UUID::NS_DNS.eq_str(s), "{s}");
+        }
+    }
+
+    #[test]
+    fn unequal_uuid_does_not_match() {
+        assert!(!UUID::NS_URL.eq_str("6ba7b810-9dad-11d1-80b4-00c04fd430c8"));
+    }
+
+    #[test]
+    fn malformed_input_does_not_match() {
+        for s in [
+            "",
+            "not-a-uuid",
+            "6ba7b810-9dad-11d1-80b4-00c04fd430c",
+            "{6ba7b810-9dad-11d1-80b4-00c04fd430c8",
+        ] {
+            assert!(!UUID::NS_DNS.eq_str(s), "{s}");
+        }
+    }
+}
@@ -0,0 +1,76 @@
+use crate::{UuidConstructionError, UUID};
+
+impl UUID {
+    /// Returns `self` if it is a UUID of version `version`, for fluent
+    /// validation of untrusted input:
+    ///
+    /// ```
+    /// use ps_uuid::UUID;
+    ///
+    /// fn session_id(s: &str) -> Result<UUID, Box<dyn std::error::Error>> {
+    ///     Ok(*s.parse::<UUID>()?.expect_version(4)?)
+    /// }
+    ///
+    /// assert!(session_id("6ba7b810-9dad-11d1-80b4-00c04fd430c8").is_err());
+    /// ```
+    ///
+    /// The version is compared as [`UUID::get_version`] decodes it, so only
+    /// UUIDs of the OSF variant can match.
+    ///
+    /// # Errors
+    /// - `VersionMismatch` is returned, carrying the decoded version, if it
+    ///   differs from `version`.
+    pub const fn expect_version(&self, version: u8) -> Result<&Self, UuidConstructionError> {
+        match self.get_version() {
+            Some(found) if found == version => Ok(self),
+            found => Err(UuidConstructionError::VersionMismatch {
+                expected: version,
+                found,
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{UuidConstructionError, UUID};
+
+    #[test]
+    fn matching_version_returns_self() {
+        let uuid = UUID::gen_v4();
+
+        assert_eq!(uuid.expect_version(4), Ok(&uuid));
+        assert_eq!(UUID::NS_DNS.expect_version(1), Ok(&UUID::NS_DNS));
+    }
+
+    #[test]
+    fn mismatching_version_reports_the_decoded_one() {
+        assert_eq!(
+            UUID::NS_DNS.expect_version(4),
+            Err(UuidConstructionError::VersionMismatch {
+                expected: 4,
+                found: Some(1),
+            })
+        );
+    }
+
+    #[test]
+    fn non_osf_uuids_never_match() {
+        assert_eq!(
+            UUID::nil().expect_version(0),
+            Err(UuidConstructionError::VersionMismatch {
+                expected: 0,
+                found: None,
+            })
+        );
+    }
+
+    #[test]
+    fn error_message_names_the_expected_version() {
+        let err = UUID::nil()
+            .expect_version(7)
+            .expect_err("nil is not version 7");
+
+        assert_eq!(err.to_string(), "Expected a version 7 UUID.");
+    }
+}
@@ -0,0 +1,71 @@
+use rand::{rng, RngCore};
+
+use crate::{helpers::byte_arrays_mut, UUID};
+
+impl UUID {
+    /// Overwrites every element of `out` with a random (v4) UUID.
+    ///
+    /// All `out.len() * 16` random bytes are drawn from the thread-local
+    /// CSPRNG in a single call, written straight into the backing storage of
+    /// `out`, then the version and variant bits of each element are patched
+    /// in.
+    pub fn fill_v4(out: &mut [Self]) {
+        rng().fill_bytes(byte_arrays_mut(out).as_flattened_mut());
+
+        for uuid in out {
+            *uuid = uuid.with_version(4);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use crate::{Variant, UUID};
+
+    #[test]
+    fn every_element_is_a_v4_osf_uuid() {
+        let mut out = [UUID::nil(); 1000];
+
+        UUID::fill_v4(&mut out);
+
+        for uuid in &out {
+            assert_eq!(uuid.get_version(), Some(4));
+            assert_eq!(uuid.get_variant(), Variant::OSF);
+        }
+    }
+
+    #[test]
+    fn elements_are_unique() {
+        let mut out = vec![UUID::nil(); 10_000];
+
+        UUID::fill_v4(&mut out);
+
+        let unique: HashSet<UUID> = out.iter().copied().collect();
+
+        assert_eq!(unique.len(), out.len(), "Duplicate UUID generated!");
+    }
+
+    #[test]
+    fn empty_slice_is_a_no_op() {
+        let mut out: [UUID; 0] = [];
+
+        UUID::fill_v4(&mut out);
+    }
+
+    #[test]
+    fn random_bits_vary_across_the_batch() {
+        // Every bit outside the version nibble and variant bits must be seen
+        // both set and cleared across the batch.
+        let mut out = [UUID::nil(); 256];
+
+        UUID::fill_v4(&mut out);
+
+        let ones = out.iter().fold(UUID::nil(), |acc, uuid| acc | *uuid);
+        let zeros = out.iter().fold(UUID::nil(), |acc, uuid| acc | !*uuid);
+
+        assert_eq!(ones, UUID::max().with_version(4));
+        assert_eq!(zeros, !UUID::nil().with_version(4));
+    }
+}
@@ -56,6 +56,21 @@ impl UUID {
     pub const fn braced(self) -> Braced {
         Braced(self)
     }
+
+    /// Returns the braced string form, equivalent to
+    /// `self.braced().to_string()`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ps_uuid::UUID;
+    ///
+    /// assert_eq!(UUID::nil().to_braced_string(), "{00000000-0000-0000-0000-000000000000}");
+    /// ```
+    #[must_use]
+    pub fn to_braced_string(&self) -> String {
+        self.braced().to_string()
+    }
 }
 
 #[cfg(test)]
@@ -119,4 +134,13 @@ mod tests {
         let parsed: UUID = braced.parse().expect("braced format should parse");
         assert_eq!(parsed, uuid);
     }
+
+    #[test]
+    fn to_braced_string_round_trips() {
+        let uuid = UUID::gen_v4();
+        let s = uuid.to_braced_string();
+
+        assert_eq!(s, uuid.braced().to_string());
+        assert_eq!(s.parse::<UUID>().expect("braced string should parse"), uuid);
+    }
 }
@@ -52,6 +52,21 @@ impl UUID {
     pub const fn simple(self) -> Simple {
         Simple(self)
     }
+
+    /// Returns the simple string form of 32 lowercase hexadecimal digits,
+    /// equivalent to `self.simple().to_string()`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ps_uuid::UUID;
+    ///
+    /// assert_eq!(UUID::NS_DNS.to_simple_string(), "6ba7b8109dad11d180b400c04fd430c8");
+    /// ```
+    #[must_use]
+    pub fn to_simple_string(&self) -> String {
+        self.simple().to_string()
+    }
 }
 
 #[cfg(test)]
@@ -108,4 +123,15 @@ mod tests {
         let parsed: UUID = simple.parse().expect("simple format should parse");
         assert_eq!(parsed, uuid);
     }
+
+    #[test]
+    fn to_simple_string_round_trips() {
+        let uuid = UUID::gen_v4();
+        let s = uuid.to_simple_string();
+
+        assert_eq!(s.len(), 32);
+        assert!(!s.contains('-'));
+        assert_eq!(s, s.to_lowercase());
+        assert_eq!(s.parse::<UUID>().expect("simple string should parse"), uuid);
+    }
 }
@@ -55,6 +55,21 @@ impl UUID {
     pub const fn urn(self) -> Urn {
         Urn(self)
     }
+
+    /// Returns the URN string form, equivalent to
+    /// `self.urn().to_string()`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ps_uuid::UUID;
+    ///
+    /// assert_eq!(UUID::nil().to_urn_string(), "urn:uuid:00000000-0000-0000-0000-000000000000");
+    /// ```
+    #[must_use]
+    pub fn to_urn_string(&self) -> String {
+        self.urn().to_string()
+    }
 }
 
 #[cfg(test)]
@@ -117,4 +132,13 @@ mod tests {
         let parsed: UUID = urn.parse().expect("URN format should parse");
         assert_eq!(parsed, uuid);
     }
+
+    #[test]
+    fn to_urn_string_round_trips() {
+        let uuid = UUID::gen_v4();
+        let s = uuid.to_urn_string();
+
+        assert_eq!(s, uuid.urn().to_string());
+        assert_eq!(s.parse::<UUID>().expect("URN string should parse"), uuid);
+    }
 }
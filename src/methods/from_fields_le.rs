@@ -0,0 +1,67 @@
+use crate::UUID;
+
+impl UUID {
+    /// Creates a UUID from four field values, the first three in
+    /// little-endian order.
+    ///
+    /// These are the integer fields of a .NET
+    /// `Guid(int, short, short, byte[])` constructor: `d1`, `d2` and `d3` are
+    /// written little-endian and `d4` is copied verbatim, which is the byte
+    /// order of a Microsoft `GUID` in memory and of
+    /// [`UUID::from_parts_dcom`]. No version or variant bits are touched.
+    ///
+    /// # Arguments
+    /// * `d1` - The first field (32 bits, little-endian)
+    /// * `d2` - The second field (16 bits, little-endian)
+    /// * `d3` - The third field (16 bits, little-endian)
+    /// * `d4` - The fourth field (64 bits as 8 bytes)
+    #[must_use]
+    pub const fn from_fields_le(d1: u32, d2: u16, d3: u16, d4: &[u8; 8]) -> Self {
+        let [t0, t1, t2, t3] = d1.to_le_bytes();
+        let [m0, m1] = d2.to_le_bytes();
+        let [h0, h1] = d3.to_le_bytes();
+        let [r0, r1, r2, r3, r4, r5, r6, r7] = *d4;
+
+        Self::from_bytes([
+            t0, t1, t2, t3, m0, m1, h0, h1, r0, r1, r2, r3, r4, r5, r6, r7,
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Variant, UUID};
+
+    #[test]
+    fn matches_from_parts_dcom() {
+        // 0xC234 already carries the DCOM variant bits, so `from_parts_dcom`
+        // leaves it unchanged.
+        let node = [0xAA, 0xBB, 0xCC, 0xDD, 0xEE, 0xFF];
+        let d4 = [0xC2, 0x34, 0xAA, 0xBB, 0xCC, 0xDD, 0xEE, 0xFF];
+
+        let dcom = UUID::from_parts_dcom(0x1234_5678, 0x9ABC, 0xDEF0, 0xC234, node);
+        let le = UUID::from_fields_le(0x1234_5678, 0x9ABC, 0xDEF0, &d4);
+
+        assert_eq!(le, dcom);
+        assert_eq!(le.get_variant(), Variant::DCOM);
+    }
+
+    #[test]
+    fn writes_the_first_three_fields_byte_swapped() {
+        let uuid = UUID::from_fields_le(
+            0x0011_2233,
+            0x4455,
+            0x6677,
+            &[0x88, 0x99, 0xAA, 0xBB, 0xCC, 0xDD, 0xEE, 0xFF],
+        );
+
+        assert_eq!(uuid.to_string(), "33221100-5544-7766-8899-aabbccddeeff");
+    }
+
+    #[test]
+    fn const_context() {
+        const UUID_CONST: UUID = UUID::from_fields_le(1, 0, 0, &[0; 8]);
+
+        assert_eq!(UUID_CONST.as_bytes()[0], 1);
+    }
+}
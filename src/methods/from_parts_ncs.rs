@@ -10,7 +10,8 @@ impl UUID {
     ///
     /// The address family is not validated: setting the NCS variant clears
     /// bit 7 of the stored byte, so a value above 127 is silently altered.
-    /// Use [`UUID::new_ncs`] to have the 0-13 range enforced.
+    /// Use [`UUID::new_ncs`] to have the 0-13 range enforced, and
+    /// [`UUID::ncs_fields`] to decode the fields again.
     ///
     /// # NCS UUID Structure
     /// - Timestamp (48 bits): Raw timestamp bytes in 4-microsecond units since 1980-01-01 00:00 UTC.
@@ -2,6 +2,22 @@ use crate::UUID;
 
 impl UUID {
     /// Build a DCE Security (v2) UUID from its individual fields.
+    ///
+    /// The fields are laid out as follows (all big-endian):
+    ///
+    /// | Bytes  | Field                                               |
+    /// |--------|-----------------------------------------------------|
+    /// | 0..4   | `local_id` (in place of `time_low`)                 |
+    /// | 4..6   | `time_mid`                                          |
+    /// | 6..8   | version `2` and the low 12 bits of `time_hi`        |
+    /// | 8      | variant `0b10` and bits 8..14 of `clock_seq`        |
+    /// | 9      | `domain` (in place of `clock_seq_low`)              |
+    /// | 10..16 | `node_id`                                           |
+    ///
+    /// Only `clock_seq_hi` survives, so the low byte of `clock_seq` is
+    /// ignored. Every input is placed verbatim, which makes this the
+    /// deterministic counterpart of [`UUID::new_v2`] for reconstructing a
+    /// known v2 UUID.
     #[must_use]
     pub fn from_parts_v2(
         domain: u8,
@@ -21,6 +37,7 @@ impl UUID {
 
 #[cfg(test)]
 mod tests {
+    #![allow(clippy::expect_used)]
     use crate::{NodeId, UUID};
 
     const fn variant_rfc_4122(byte: u8) -> bool {
@@ -60,4 +77,31 @@ mod tests {
         // Public API
         assert_eq!(u.get_version(), Some(2));
     }
+
+    #[test]
+    fn clock_seq_hi_lands_in_byte_8_and_its_low_byte_is_ignored() {
+        let node_id = [1, 2, 3, 4, 5, 6];
+
+        let u = UUID::from_parts_v2(0x01, 0, 0, 0, 0x2A_FF, node_id);
+
+        assert_eq!(u.bytes[8], 0x80 | 0x2A);
+        assert_eq!(u.bytes[9], 0x01);
+        assert_eq!(u, UUID::from_parts_v2(0x01, 0, 0, 0, 0x2A_00, node_id));
+    }
+
+    #[test]
+    fn reconstructs_new_v2() {
+        let node_id = [1, 2, 3, 4, 5, 6];
+        let time = std::time::UNIX_EPOCH + std::time::Duration::from_secs(1_700_000_000);
+        let generated = UUID::new_v2(0x01, 1000, time, 0x1234, node_id).expect("valid timestamp");
+
+        let b = generated.as_bytes();
+        let time_mid = u16::from_be_bytes([b[4], b[5]]);
+        let time_hi = u16::from_be_bytes([b[6], b[7]]);
+
+        assert_eq!(
+            UUID::from_parts_v2(0x01, 1000, time_mid, time_hi, 0x1234, node_id),
+            generated
+        );
+    }
 }
@@ -3,10 +3,26 @@ use crate::UUID;
 impl UUID {
     /// Constructs a Version 4 (random) UUID from 16 bytes.
     ///
-    /// The version and variant fields are set according to RFC 4122.
+    /// The version and variant fields are set according to RFC 4122; the
+    /// remaining 122 bits are taken from `random` unchanged.
+    ///
+    /// Unlike [`UUID::gen_v4`], the result depends only on the input, so
+    /// this is the constructor to use with an external entropy source or a
+    /// seeded RNG in deterministic tests. The caller is responsible for the
+    /// bytes actually being random.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ps_uuid::UUID;
+    ///
+    /// let uuid = UUID::from_parts_v4([0xAB; 16]);
+    ///
+    /// assert_eq!(uuid.to_string(), "abababab-abab-4bab-abab-abababababab");
+    /// ```
     #[must_use]
-    pub const fn from_parts_v4(bytes: [u8; 16]) -> Self {
-        Self::from_bytes(bytes).with_version(4)
+    pub const fn from_parts_v4(random: [u8; 16]) -> Self {
+        Self::from_bytes(random).with_version(4)
     }
 }
 
@@ -74,6 +90,27 @@ mod tests {
     fn version_and_variant_are_reported() {
         let uuid = UUID::from_parts_v4([0u8; 16]);
         assert_eq!(uuid.get_version(), Some(4));
-        // If you have a variant() method, you can also check that here.
+        assert_eq!(uuid.get_variant(), crate::Variant::OSF);
+    }
+
+    #[test]
+    fn identical_inputs_yield_identical_uuids() {
+        let random: [u8; 16] = rand::random();
+
+        assert_eq!(UUID::from_parts_v4(random), UUID::from_parts_v4(random));
+        assert_ne!(
+            UUID::from_parts_v4([0x00; 16]),
+            UUID::from_parts_v4([0xFF; 16])
+        );
+    }
+
+    #[test]
+    fn const_context() {
+        const UUID_CONST: UUID = UUID::from_parts_v4([0; 16]);
+
+        assert_eq!(
+            UUID_CONST.to_string(),
+            "00000000-0000-4000-8000-000000000000"
+        );
     }
 }
@@ -24,6 +24,9 @@ impl UUID {
     /// The function never fails; it masks super-fluous upper bits and then
     /// calls `.with_version(7)` which patches in both the version nibble
     /// **and** the RFC-4122 variant.
+    ///
+    /// To fill `rand_a` and `rand_b` from a random number generator, use
+    /// [`UUID::gen_v7_at_millis`].
     #[inline]
     #[must_use]
     pub fn from_parts_v7(unix_ts_ms: u64, rand_a: u16, rand_b: u64) -> Self {
@@ -0,0 +1,105 @@
+use crate::{UuidParseError, UUID};
+
+use super::to_short::{SHORT_ALPHABET, SHORT_LEN};
+
+impl UUID {
+    /// Decodes the 22-character base-57 `ShortUUID` form produced by
+    /// [`UUID::to_short`].
+    ///
+    /// # Errors
+    ///
+    /// - [`UuidParseError::InvalidLength`] if `s` is not exactly 22 bytes.
+    /// - [`UuidParseError::InvalidCharacter`] if `s` contains a character
+    ///   outside the base-57 alphabet.
+    /// - [`UuidParseError::ValueOutOfRange`] if `s` encodes a value wider
+    ///   than 128 bits.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ps_uuid::UUID;
+    ///
+    /// let uuid = UUID::from_short("CXc85b4rqinB7s5J52TRYb").unwrap();
+    ///
+    /// assert_eq!(uuid.to_string(), "3b1f8b40-222c-4a6e-b77e-779d5a94e21c");
+    /// ```
+    pub fn from_short(s: &str) -> Result<Self, UuidParseError> {
+        if s.len() != SHORT_LEN {
+            return Err(UuidParseError::InvalidLength);
+        }
+
+        let mut value: u128 = 0;
+
+        for (idx, ch) in s.char_indices() {
+            let digit = SHORT_ALPHABET
+                .iter()
+                .position(|&b| char::from(b) == ch)
+                .ok_or(UuidParseError::InvalidCharacter { ch, idx })?;
+
+            value = value
+                .checked_mul(57)
+                .and_then(|value| value.checked_add(digit as u128))
+                .ok_or(UuidParseError::ValueOutOfRange)?;
+        }
+
+        Ok(Self::from_u128(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+    use crate::{UuidParseError, UUID};
+
+    #[test]
+    fn decodes_known_short_uuids() {
+        assert_eq!(
+            UUID::from_short("CXc85b4rqinB7s5J52TRYb").map(|uuid| uuid.to_string()),
+            Ok("3b1f8b40-222c-4a6e-b77e-779d5a94e21c".to_owned())
+        );
+        assert_eq!(UUID::from_short("MAnkyno2VCnFzuVMWtxBda"), Ok(UUID::NS_DNS));
+    }
+
+    #[test]
+    fn roundtrips() {
+        for uuid in [UUID::nil(), UUID::max(), UUID::gen_v4(), UUID::from(56u128)] {
+            assert_eq!(UUID::from_short(&uuid.to_short()), Ok(uuid));
+        }
+    }
+
+    #[test]
+    fn rejects_wrong_length() {
+        assert_eq!(
+            UUID::from_short("CXc85b4rqinB7s5J52TRY"),
+            Err(UuidParseError::InvalidLength)
+        );
+        assert_eq!(
+            UUID::from_short("CXc85b4rqinB7s5J52TRYbb"),
+            Err(UuidParseError::InvalidLength)
+        );
+    }
+
+    #[test]
+    fn rejects_characters_outside_the_alphabet() {
+        assert_eq!(
+            UUID::from_short("CXc85b4rqinB7s5J52TRY0"),
+            Err(UuidParseError::InvalidCharacter { ch: '0', idx: 21 })
+        );
+        assert_eq!(
+            UUID::from_short("lXc85b4rqinB7s5J52TRYb"),
+            Err(UuidParseError::InvalidCharacter { ch: 'l', idx: 0 })
+        );
+    }
+
+    #[test]
+    fn rejects_values_beyond_128_bits() {
+        assert_eq!(
+            UUID::from_short("oZEq7ovRbLq6UnGMPwc8B6"),
+            Err(UuidParseError::ValueOutOfRange)
+        );
+        assert_eq!(
+            UUID::from_short("zzzzzzzzzzzzzzzzzzzzzz"),
+            Err(UuidParseError::ValueOutOfRange)
+        );
+    }
+}
@@ -0,0 +1,132 @@
+use crate::{UuidParseError, UUID, UUID_BYTES};
+
+/// Decodes one ASCII hexadecimal digit.
+const fn hex_value(digit: u8) -> Option<u8> {
+    match digit {
+        b'0'..=b'9' => Some(digit - b'0'),
+        b'a'..=b'f' => Some(digit - b'a' + 10),
+        b'A'..=b'F' => Some(digit - b'A' + 10),
+        _ => None,
+    }
+}
+
+impl UUID {
+    /// Parses exactly 32 hexadecimal digits, the simple form, in either
+    /// case.
+    ///
+    /// Unlike [`FromStr`](std::str::FromStr), no other spelling is detected
+    /// or accepted: hyphens, braces, a `urn:uuid:` or `0x` prefix are all
+    /// errors. For input known to be in the simple form, such as a
+    /// fixed-width database column, this skips the format detection and
+    /// decodes the digits pairwise.
+    ///
+    /// # Errors
+    ///
+    /// - [`UuidParseError::InvalidLength`] if `s` is not 32 bytes long.
+    /// - [`UuidParseError::InvalidCharacter`] at the first byte that is not
+    ///   a hexadecimal digit.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ps_uuid::UUID;
+    ///
+    /// assert_eq!(
+    ///     UUID::from_simple("6ba7b8109dad11d180b400c04fd430c8"),
+    ///     Ok(UUID::NS_DNS)
+    /// );
+    /// assert!(UUID::from_simple("6ba7b810-9dad-11d1-80b4-00c04fd430c8").is_err());
+    /// ```
+    pub fn from_simple(s: &str) -> Result<Self, UuidParseError> {
+        let digits: &[u8; 2 * UUID_BYTES] = s
+            .as_bytes()
+            .try_into()
+            .map_err(|_| UuidParseError::InvalidLength)?;
+
+        let mut bytes = [0u8; UUID_BYTES];
+
+        for (idx, digit) in digits.iter().enumerate() {
+            // Every byte before `idx` is an ASCII digit, so `idx` is also
+            // the character index and a character boundary.
+            let Some(value) = hex_value(*digit) else {
+                let ch = s[idx..].chars().next().unwrap_or_default();
+
+                return Err(UuidParseError::InvalidCharacter { ch, idx });
+            };
+
+            bytes[idx / 2] |= value << (4 * (1 - idx % 2));
+        }
+
+        Ok(Self::from_bytes(bytes))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{UuidParseError, UUID};
+
+    #[test]
+    fn parses_32_hex_digits() {
+        assert_eq!(
+            UUID::from_simple("6ba7b8109dad11d180b400c04fd430c8"),
+            Ok(UUID::NS_DNS)
+        );
+        assert_eq!(
+            UUID::from_simple("6BA7B8109DAD11D180B400C04FD430C8"),
+            Ok(UUID::NS_DNS)
+        );
+        assert_eq!(
+            UUID::from_simple("ffffffffffffffffffffffffffffffff"),
+            Ok(UUID::max())
+        );
+    }
+
+    #[test]
+    fn agrees_with_from_str() {
+        for _ in 0..100 {
+            let uuid = UUID::gen_v4();
+            let simple = uuid.to_string().replace('-', "");
+
+            assert_eq!(UUID::from_simple(&simple), Ok(uuid));
+        }
+    }
+
+    #[test]
+    fn other_spellings_are_rejected() {
+        for s in [
+            "6ba7b810-9dad-11d1-80b4-00c04fd430c8",
+            "{6ba7b8109dad11d180b400c04fd430c8}",
+            "urn:uuid:6ba7b810-9dad-11d1-80b4-00c04fd430c8",
+            "0x6ba7b8109dad11d180b400c04fd430c8",
+            "6ba7b8109dad11d180b400c04fd430c",
+            "",
+        ] {
+            assert_eq!(
+                UUID::from_simple(s),
+                Err(UuidParseError::InvalidLength),
+                "{s}"
+            );
+        }
+    }
+
+    #[test]
+    fn invalid_hex_is_rejected() {
+        assert_eq!(
+            UUID::from_simple("6ba7b8109dad11d180b400c04fd430cg"),
+            Err(UuidParseError::InvalidCharacter { ch: 'g', idx: 31 })
+        );
+        assert_eq!(
+            UUID::from_simple("-ba7b8109dad11d180b400c04fd430c8"),
+            Err(UuidParseError::InvalidCharacter { ch: '-', idx: 0 })
+        );
+    }
+
+    #[test]
+    fn non_ascii_is_reported_whole() {
+        // 'é' is two bytes, so 31 characters fill 32 bytes.
+        assert_eq!(
+            UUID::from_simple("6ba7b810é9dad11d180b400c04fd430"),
+            Err(UuidParseError::InvalidCharacter { ch: 'é', idx: 8 })
+        );
+    }
+}
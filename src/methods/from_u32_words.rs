@@ -0,0 +1,46 @@
+use crate::UUID;
+
+impl UUID {
+    /// Creates a UUID from four `u32` words, the most significant first,
+    /// each in big-endian byte order, as graphics APIs and some RPC schemas
+    /// expose UUIDs.
+    ///
+    /// This is the inverse of [`UUID::to_u32_words`].
+    #[must_use]
+    pub const fn from_u32_words(w: [u32; 4]) -> Self {
+        let [a0, a1, a2, a3] = w[0].to_be_bytes();
+        let [b0, b1, b2, b3] = w[1].to_be_bytes();
+        let [c0, c1, c2, c3] = w[2].to_be_bytes();
+        let [d0, d1, d2, d3] = w[3].to_be_bytes();
+
+        Self::from_bytes([
+            a0, a1, a2, a3, b0, b1, b2, b3, c0, c1, c2, c3, d0, d1, d2, d3,
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::UUID;
+
+    #[test]
+    fn last_word_is_least_significant() {
+        assert_eq!(UUID::from_u32_words([0, 0, 0, 1]), UUID::from(1u128));
+        assert_eq!(UUID::from_u32_words([1, 0, 0, 0]), UUID::from(1u128 << 96));
+    }
+
+    #[test]
+    fn roundtrip_with_to_u32_words() {
+        let words = [0x6BA7_B810, 0x9DAD_11D1, 0x80B4_00C0, 0x4FD4_30C8];
+
+        assert_eq!(UUID::from_u32_words(words), UUID::NS_DNS);
+        assert_eq!(UUID::from_u32_words(words).to_u32_words(), words);
+    }
+
+    #[test]
+    fn const_context() {
+        const UUID_CONST: UUID = UUID::from_u32_words([u32::MAX; 4]);
+
+        assert_eq!(UUID_CONST, UUID::max());
+    }
+}
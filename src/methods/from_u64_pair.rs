@@ -0,0 +1,37 @@
+use crate::UUID;
+
+impl UUID {
+    /// Creates a UUID from two `u64` integers, the high half first, each in
+    /// big-endian byte order.
+    ///
+    /// This is the inverse of [`UUID::to_u64_pair`].
+    #[must_use]
+    pub const fn from_u64_pair(hi: u64, lo: u64) -> Self {
+        Self::from_u128(((hi as u128) << 64) | lo as u128)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::UUID;
+
+    #[test]
+    fn low_half_is_least_significant() {
+        assert_eq!(UUID::from_u64_pair(0, 1), UUID::from(1u128));
+        assert_eq!(UUID::from_u64_pair(1, 0), UUID::from(1u128 << 64));
+    }
+
+    #[test]
+    fn roundtrip_with_to_u64_pair() {
+        let pair = (0xfedc_ba98_7654_3210, 0x0123_4567_89ab_cdef);
+
+        assert_eq!(UUID::from_u64_pair(pair.0, pair.1).to_u64_pair(), pair);
+    }
+
+    #[test]
+    fn const_context() {
+        const UUID_CONST: UUID = UUID::from_u64_pair(u64::MAX, u64::MAX);
+
+        assert_eq!(UUID_CONST, UUID::max());
+    }
+}
@@ -5,7 +5,8 @@ use crate::{UuidConstructionError, STATE, UUID};
 impl UUID {
     /// Generates a Microsoft (DCOM) variant UUID using the current system time.
     ///
-    /// The node ID is supplied by the caller. The timestamp and clock sequence
+    /// The node ID is supplied by the caller; [`UUID::gen_dcom_global`] uses
+    /// the process-wide node ID instead. The timestamp and clock sequence
     /// are drawn from the shared [`STATE`]: repeated calls within the same
     /// 100-nanosecond tick advance the clock sequence, and once the 13
     /// sequence bits the DCOM variant retains are exhausted (8192 UUIDs in one
@@ -24,6 +25,12 @@ impl UUID {
     ///   the error persists until a reading at or after 1601-01-01 is
     ///   adopted, and on the other platforms it never arises from a fresh
     ///   state.
+    /// - [`UuidConstructionError::RateExceeded`] is returned once the shared
+    ///   state has borrowed [`State::MAX_BORROWED_TICKS`] ticks ahead of the
+    ///   last adopted clock reading; see [`State::next`].
+    ///
+    /// [`State::MAX_BORROWED_TICKS`]: crate::State::MAX_BORROWED_TICKS
+    /// [`State::next`]: crate::State::next
     ///
     /// Because the state is shared with the version-1 family, adoption of
     /// clock readings is capped at the 60-bit RFC 4122 range (through
@@ -31,7 +38,7 @@ impl UUID {
     /// beyond the cap is never adopted, and generation continues from the last
     /// issued tick, so `TimestampOverflow` cannot occur from clock input.
     pub fn gen_dcom(node_id: [u8; 6]) -> Result<Self, UuidConstructionError> {
-        let (timestamp, clock_seq) = STATE.lock().next(SystemTime::now());
+        let (timestamp, clock_seq) = STATE.lock().next(SystemTime::now())?;
 
         Self::new_dcom(timestamp, clock_seq, node_id)
     }
@@ -0,0 +1,66 @@
+use std::time::SystemTime;
+
+use crate::{State, UuidConstructionError, STATE, UUID};
+
+/// Generates a DCOM UUID from the next tick of `state` and its node ID.
+fn gen_dcom_with(state: &mut State, now: SystemTime) -> Result<UUID, UuidConstructionError> {
+    let (timestamp, clock_seq) = state.next(now)?;
+
+    UUID::new_dcom(timestamp, clock_seq, *state.node_id())
+}
+
+impl UUID {
+    /// Generates a Microsoft (DCOM) variant UUID using the current system
+    /// time and the process-wide node ID held in [`STATE`].
+    ///
+    /// This is [`UUID::gen_dcom`] with the node taken from [`STATE`] rather
+    /// than from the caller, like [`UUID::gen_v1`] and [`UUID::gen_v6`], so
+    /// pinning the node with [`UUID::init_node_from_env`] or
+    /// [`State::set_node_id`] applies to DCOM UUIDs as well.
+    ///
+    /// [`State::set_node_id`]: crate::State::set_node_id
+    ///
+    /// # Errors
+    /// The same as [`UUID::gen_dcom`].
+    pub fn gen_dcom_global() -> Result<Self, UuidConstructionError> {
+        gen_dcom_with(&mut STATE.lock(), SystemTime::now())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+    use std::time::SystemTime;
+
+    use super::gen_dcom_with;
+    use crate::{NodeId, State, Variant, UUID};
+
+    #[test]
+    fn embeds_the_state_node_and_dcom_variant() {
+        // A private state, so concurrent resets of `STATE` cannot interfere.
+        let mut state = State::default();
+        let node = NodeId::from([0x03, 0x11, 0x22, 0x33, 0x44, 0x55]);
+
+        state.set_node_id(node);
+
+        let uuid = gen_dcom_with(&mut state, SystemTime::now()).expect("generation must succeed");
+
+        assert_eq!(uuid.get_variant(), Variant::DCOM);
+        assert_eq!(uuid.as_bytes()[10..16], *node);
+    }
+
+    #[test]
+    fn global_generation_yields_dcom_uuids() {
+        let uuid = UUID::gen_dcom_global().expect("generation must succeed");
+
+        assert_eq!(uuid.get_variant(), Variant::DCOM);
+    }
+
+    #[test]
+    fn successive_uuids_are_distinct() {
+        let first = UUID::gen_dcom_global().expect("generation must succeed");
+        let second = UUID::gen_dcom_global().expect("generation must succeed");
+
+        assert_ne!(first, second);
+    }
+}
@@ -1,6 +1,6 @@
 use std::time::SystemTime;
 
-use crate::UUID;
+use crate::{NcsAddress, UUID};
 
 use super::NcsUuidError;
 
@@ -9,8 +9,12 @@ impl UUID {
     ///
     /// # Errors
     ///
-    /// This method returns [`NcsUuidError::TimestampOverflow`] after 2015.
-    pub fn gen_ncs(address_family: u8, address: &[u8; 7]) -> Result<Self, NcsUuidError> {
+    /// This method returns [`NcsUuidError::TimestampOverflow`] from
+    /// 2015-09-05T05:58:26.842624Z onward.
+    pub fn gen_ncs(
+        address_family: u8,
+        address: impl Into<NcsAddress>,
+    ) -> Result<Self, NcsUuidError> {
         Self::new_ncs(SystemTime::now(), address_family, address)
     }
 }
@@ -22,7 +26,7 @@ mod tests {
     #[test]
     fn overflow() {
         assert!(matches!(
-            UUID::gen_ncs(5, &[5, 4, 8, 6, 0, 0, 0]),
+            UUID::gen_ncs(5, [5, 4, 8, 6, 0, 0, 0]),
             Err(NcsUuidError::TimestampOverflow)
         ));
     }
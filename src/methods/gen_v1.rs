@@ -5,17 +5,33 @@ impl UUID {
     /// Generate an RFC 4122 version-1 (time-based) UUID.
     ///
     /// The current system time and process-wide `NodeId` and clock sequence are used.
+    /// Unless replaced, the node is [`NodeId::acquire`], which may be this
+    /// host's MAC address and so identifies the machine that generated the
+    /// UUID; see [`UUID::init_node_from_env`] to install another.
+    ///
+    /// [`NodeId::acquire`]: crate::NodeId::acquire
     ///
     /// # Errors
     /// - `TimestampOverflow` is returned once the shared generator state has
     ///   exhausted the 60-bit timestamp range, which ends 5236-03-31.
+    /// - `RateExceeded` is returned once the shared generator state has
+    ///   borrowed [`State::MAX_BORROWED_TICKS`] ticks ahead of the last
+    ///   adopted clock reading; see [`State::next`].
+    ///
+    /// [`State::MAX_BORROWED_TICKS`]: crate::State::MAX_BORROWED_TICKS
+    /// [`State::next`]: crate::State::next
     ///
     /// A clock reading before 1582-10-15 or beyond the representable range is
     /// never adopted; generation continues from the last issued tick.
+    ///
+    /// A coarse clock, such as one with millisecond resolution, is handled
+    /// the same way as a frozen one: up to 2¹³ clock-sequence values are
+    /// issued per tick before the next tick is borrowed, so one millisecond
+    /// of 10 000 ticks holds about 82 million distinct UUIDs.
     pub fn gen_v1() -> Result<Self, UuidConstructionError> {
         let mut guard = STATE.lock();
 
-        let (timestamp, clock_seq) = guard.next(SystemTime::now());
+        let (timestamp, clock_seq) = guard.next(SystemTime::now())?;
         let node_id = guard.node_id();
 
         drop(guard);
@@ -1,16 +1,14 @@
-use rand::fill;
+use rand::rng;
 
 use crate::UUID;
 
 impl UUID {
-    /// Generates a random (v4) UUID.
+    /// Generates a random (v4) UUID from the thread-local CSPRNG.
+    ///
+    /// See [`UUID::gen_v4_from`] to supply a different entropy source.
     #[must_use]
     pub fn gen_v4() -> Self {
-        let mut uuid = Self::nil();
-
-        fill(&mut uuid.bytes);
-
-        uuid.with_version(4)
+        Self::gen_v4_from(&mut rng())
     }
 }
 
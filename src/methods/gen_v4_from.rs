@@ -0,0 +1,82 @@
+use rand::RngCore;
+
+use crate::UUID;
+
+impl UUID {
+    /// Generates a random (v4) UUID from a caller-supplied entropy source,
+    /// such as a hardware RNG, passed as a trait object.
+    ///
+    /// [`UUID::gen_v4`] is this function applied to the thread-local
+    /// CSPRNG; [`UUID::new_v4`] is its generic counterpart.
+    ///
+    /// Exactly 16 bytes are drawn with [`RngCore::fill_bytes`], 122 of which
+    /// end up in the UUID. Its uniqueness rests entirely on `source`: it
+    /// must be cryptographically secure and unpredictable, and must never
+    /// repeat its output, e.g. across forked processes or restored VM
+    /// snapshots. A seeded PRNG yields reproducible UUIDs, which is only
+    /// appropriate in tests.
+    #[must_use]
+    pub fn gen_v4_from(source: &mut dyn RngCore) -> Self {
+        Self::new_v4(source)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::RngCore;
+
+    use crate::{Variant, UUID};
+
+    /// A deterministic source emitting 0, 1, 2, … as bytes.
+    struct Counting(u8);
+
+    impl RngCore for Counting {
+        fn next_u32(&mut self) -> u32 {
+            let mut bytes = [0; 4];
+            self.fill_bytes(&mut bytes);
+            u32::from_le_bytes(bytes)
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            let mut bytes = [0; 8];
+            self.fill_bytes(&mut bytes);
+            u64::from_le_bytes(bytes)
+        }
+
+        fn fill_bytes(&mut self, dst: &mut [u8]) {
+            for byte in dst {
+                *byte = self.0;
+                self.0 = self.0.wrapping_add(1);
+            }
+        }
+    }
+
+    #[test]
+    fn custom_source_yields_a_v4_uuid() {
+        let uuid = UUID::gen_v4_from(&mut Counting(0));
+
+        assert_eq!(uuid.get_version(), Some(4));
+        assert_eq!(uuid.get_variant(), Variant::OSF);
+        assert_eq!(uuid.to_string(), "00010203-0405-4607-8809-0a0b0c0d0e0f");
+    }
+
+    #[test]
+    fn draws_exactly_sixteen_bytes() {
+        let mut source = Counting(0);
+
+        let first = UUID::gen_v4_from(&mut source);
+        let second = UUID::gen_v4_from(&mut source);
+
+        assert_eq!(source.0, 32);
+        assert_eq!(second.as_bytes()[0], 16);
+        assert_ne!(first, second);
+    }
+
+    #[test]
+    fn matches_new_v4_for_the_same_source() {
+        assert_eq!(
+            UUID::gen_v4_from(&mut Counting(7)),
+            UUID::new_v4(&mut Counting(7))
+        );
+    }
+}
@@ -0,0 +1,61 @@
+use rand::fill;
+
+use crate::{UUID, UUID_BYTES};
+
+impl UUID {
+    /// Writes a fresh random (v4) UUID into `out`.
+    ///
+    /// The random bytes are drawn straight into the caller's buffer and the
+    /// version and variant bits are patched in place, so no intermediate
+    /// [`UUID`] is built and copied. This suits buffers handed across an FFI
+    /// boundary; the result is laid out exactly as [`UUID::as_bytes`] would
+    /// return it.
+    pub fn gen_v4_into(out: &mut [u8; UUID_BYTES]) {
+        fill(out);
+
+        out[6] = (out[6] & 0x0F) | 0x40;
+        out[8] = (out[8] & 0x3F) | 0x80;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Variant, UUID};
+
+    #[test]
+    fn writes_a_v4_osf_uuid() {
+        for _ in 0..100 {
+            let mut out = [0xFF; 16];
+
+            UUID::gen_v4_into(&mut out);
+
+            let uuid = UUID::from_bytes(out);
+
+            assert_eq!(uuid.get_version(), Some(4));
+            assert_eq!(uuid.get_variant(), Variant::OSF);
+        }
+    }
+
+    #[test]
+    fn successive_calls_differ() {
+        let mut first = [0; 16];
+        let mut second = [0; 16];
+
+        UUID::gen_v4_into(&mut first);
+        UUID::gen_v4_into(&mut second);
+
+        assert_ne!(first, second);
+    }
+
+    #[test]
+    fn previous_contents_are_overwritten() {
+        let mut zeros = [0x00; 16];
+        let mut ones = [0xFF; 16];
+
+        UUID::gen_v4_into(&mut zeros);
+        UUID::gen_v4_into(&mut ones);
+
+        assert_ne!(zeros, [0x00; 16]);
+        assert_ne!(ones, [0xFF; 16]);
+    }
+}
@@ -0,0 +1,93 @@
+use rand::RngCore;
+
+use crate::{UUID, UUID_BYTES};
+
+impl UUID {
+    /// Generates a random (v4) UUID whose leading bytes are copied from
+    /// `prefix`, making test and debugging IDs easy to recognize.
+    ///
+    /// At most 16 bytes of `prefix` are used; the remaining bytes are drawn
+    /// from `rng`. The version and variant bits are then set as for any v4
+    /// UUID, so a prefix reaching byte 6 has its high nibble replaced by
+    /// `4`, and one reaching byte 8 has its two high bits replaced by `10`.
+    ///
+    /// Every prefix byte reduces the UUID's randomness by eight bits, so
+    /// such IDs are unsuitable wherever uniqueness relies on v4 entropy.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ps_uuid::UUID;
+    ///
+    /// let uuid = UUID::gen_v4_with_prefix(&[0xDE, 0xAD], &mut rand::rng());
+    ///
+    /// assert!(uuid.to_string().starts_with("dead"));
+    /// assert_eq!(uuid.get_version(), Some(4));
+    /// ```
+    #[must_use]
+    pub fn gen_v4_with_prefix<R: RngCore + ?Sized>(prefix: &[u8], rng: &mut R) -> Self {
+        let mut uuid = Self::nil();
+        let len = prefix.len().min(UUID_BYTES);
+
+        uuid.bytes[..len].copy_from_slice(&prefix[..len]);
+        rng.fill_bytes(&mut uuid.bytes[len..]);
+
+        uuid.with_version(4)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use crate::{Variant, UUID};
+
+    #[test]
+    fn prefix_bytes_are_preserved_outside_version_and_variant() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let prefix = [0xDE, 0xAD, 0xBE, 0xEF, 0x01, 0x02, 0xFF, 0x03, 0xFF, 0x04];
+
+        let uuid = UUID::gen_v4_with_prefix(&prefix, &mut rng);
+
+        assert_eq!(uuid.get_version(), Some(4));
+        assert_eq!(uuid.get_variant(), Variant::OSF);
+
+        for (i, &byte) in prefix.iter().enumerate() {
+            match i {
+                6 => assert_eq!(uuid.as_bytes()[6], 0x4F),
+                8 => assert_eq!(uuid.as_bytes()[8], 0xBF),
+                _ => assert_eq!(uuid.as_bytes()[i], byte, "byte {i}"),
+            }
+        }
+    }
+
+    #[test]
+    fn remaining_bytes_are_random() {
+        let mut rng = StdRng::seed_from_u64(8);
+
+        let a = UUID::gen_v4_with_prefix(&[0xDE, 0xAD], &mut rng);
+        let b = UUID::gen_v4_with_prefix(&[0xDE, 0xAD], &mut rng);
+
+        assert_eq!(a.as_bytes()[..2], b.as_bytes()[..2]);
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn long_prefixes_are_truncated() {
+        let mut rng = StdRng::seed_from_u64(9);
+
+        let uuid = UUID::gen_v4_with_prefix(&[0xAB; 20], &mut rng);
+
+        assert_eq!(uuid, UUID::from_parts_v4([0xAB; 16]));
+    }
+
+    #[test]
+    fn empty_prefix_is_a_plain_v4() {
+        let mut rng = StdRng::seed_from_u64(10);
+
+        assert_eq!(
+            UUID::gen_v4_with_prefix(&[], &mut rng),
+            UUID::new_v4(&mut StdRng::seed_from_u64(10))
+        );
+    }
+}
@@ -6,17 +6,34 @@ impl UUID {
     ///
     /// The current system time together with the process-wide `NodeId`
     /// and clock sequence held in the global `STATE` are used.
+    /// Unless replaced, the node is [`NodeId::acquire`], which may be this
+    /// host's MAC address and so identifies the machine that generated the
+    /// UUID; see [`UUID::init_node_from_env`] to install another.
+    ///
+    /// [`NodeId::acquire`]: crate::NodeId::acquire
     ///
     /// # Errors
     /// - `TimestampOverflow` is returned once the shared generator state has
     ///   exhausted the 60-bit timestamp range, which ends 5236-03-31.
+    /// - `RateExceeded` is returned once the shared generator state has
+    ///   borrowed [`State::MAX_BORROWED_TICKS`] ticks ahead of the last
+    ///   adopted clock reading; see [`State::next`].
+    ///
+    /// [`State::MAX_BORROWED_TICKS`]: crate::State::MAX_BORROWED_TICKS
+    /// [`State::next`]: crate::State::next
     ///
     /// A clock reading before 1582-10-15 or beyond the representable range is
     /// never adopted; generation continues from the last issued tick.
+    ///
+    /// Version 6 draws from the same (tick, clock sequence) sequence as
+    /// [`UUID::gen_v1`], so it has the same limit under rate pressure: a
+    /// frozen clock yields 2¹³ × ([`State::MAX_BORROWED_TICKS`] + 1) =
+    /// 81 928 192 distinct UUIDs, after which `RateExceeded` is returned
+    /// until the clock advances.
     pub fn gen_v6() -> Result<Self, UuidConstructionError> {
         let mut guard = STATE.lock();
 
-        let (timestamp, clock_seq) = guard.next(SystemTime::now());
+        let (timestamp, clock_seq) = guard.next(SystemTime::now())?;
         let node_id = guard.node_id();
 
         drop(guard);
@@ -105,4 +122,46 @@ mod tests {
             h.join().expect("thread panicked");
         }
     }
+
+    /// Drives a private [`State`] against a frozen clock up to its
+    /// documented limit, checking every issued v6 UUID for uniqueness:
+    /// timestamps never decrease, and no clock sequence repeats within a
+    /// timestamp.
+    ///
+    /// [`State`]: crate::State
+    #[test]
+    fn frozen_clock_yields_unique_v6_uuids_until_rate_exceeded() {
+        let mut state = crate::State::default();
+
+        let frozen = std::time::UNIX_EPOCH + std::time::Duration::from_secs(1_700_000_000);
+        let limit = (u64::from(crate::State::MAX_BORROWED_TICKS) + 1) << 13;
+        let node_id = state.node_id().bytes;
+
+        let mut last_high = 0u64;
+        let mut seen = vec![false; 1 << 14];
+
+        for _ in 0..limit {
+            let (timestamp, clock_seq) = state
+                .next(frozen)
+                .expect("the documented limit must not be reached");
+            let uuid =
+                UUID::new_v6(timestamp, clock_seq, node_id).expect("timestamp must be encodable");
+
+            let (high, low) = uuid.to_u64_pair();
+            let seq = usize::try_from((low >> 48) & 0x3FFF).expect("14 bits fit usize");
+
+            assert!(high >= last_high, "Timestamps must not decrease.");
+
+            if high > last_high {
+                seen.fill(false);
+                last_high = high;
+            }
+
+            assert!(!seen[seq], "Duplicate UUID generated!");
+
+            seen[seq] = true;
+        }
+
+        assert_eq!(state.next(frozen), Err(UuidConstructionError::RateExceeded));
+    }
 }
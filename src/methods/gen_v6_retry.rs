@@ -0,0 +1,123 @@
+use std::{thread, time::Duration};
+
+use crate::{UuidConstructionError, UUID};
+
+/// How long to wait before retrying: a tenth of the one-millisecond
+/// borrowing window of [`State::next`](crate::State::next), so a retry
+/// usually finds the clock moved past the borrowed ticks.
+const RETRY_DELAY: Duration = Duration::from_micros(100);
+
+/// Calls `attempt` until it succeeds, fails with an error other than
+/// `RateExceeded`, or has been retried `max_retries` times, sleeping
+/// [`RETRY_DELAY`] before each retry.
+fn retry<T>(
+    max_retries: u32,
+    mut attempt: impl FnMut() -> Result<T, UuidConstructionError>,
+) -> Result<T, UuidConstructionError> {
+    let mut retries = 0;
+
+    loop {
+        match attempt() {
+            Err(UuidConstructionError::RateExceeded) if retries < max_retries => {
+                retries += 1;
+                thread::sleep(RETRY_DELAY);
+            }
+            result => return result,
+        }
+    }
+}
+
+impl UUID {
+    /// Generate a version-6 UUID like [`UUID::gen_v6`], sleeping briefly
+    /// and retrying, up to `max_retries` times, while generation is
+    /// transiently refused.
+    ///
+    /// The only transient condition is `RateExceeded`: the shared state has
+    /// borrowed a millisecond of ticks ahead of a frozen, coarse, or
+    /// backward-stepped clock, and recovers as soon as the clock advances
+    /// past them. Each retry waits 100 µs. A clock stepping backwards does
+    /// not fail generation by itself, since the state keeps issuing from its
+    /// last tick.
+    ///
+    /// # Errors
+    /// - `RateExceeded` is returned if generation is still refused after
+    ///   `max_retries` retries.
+    /// - `TimestampOverflow` is returned immediately, without retrying: the
+    ///   60-bit timestamp range ends 5236-03-31, and waiting cannot help.
+    pub fn gen_v6_retry(max_retries: u32) -> Result<Self, UuidConstructionError> {
+        retry(max_retries, Self::gen_v6)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use super::retry;
+    use crate::{State, UuidConstructionError, UUID};
+
+    /// Exhausts a private [`State`] at a frozen reading, then retries while
+    /// an injected clock first steps backwards and later recovers.
+    fn recover_with_budget(max_retries: u32) -> Result<UUID, UuidConstructionError> {
+        let mut state = State::default();
+        let frozen = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+
+        while state.next(frozen).is_ok() {}
+
+        let mut readings = [
+            frozen - Duration::from_secs(1),
+            frozen - Duration::from_secs(1),
+            frozen,
+            frozen + Duration::from_secs(1),
+        ]
+        .into_iter();
+
+        retry(max_retries, || {
+            let reading = readings.next().expect("the budget must bound the attempts");
+            let (timestamp, clock_seq) = state.next(reading)?;
+
+            UUID::new_v6(timestamp, clock_seq, [1, 2, 3, 4, 5, 6])
+        })
+    }
+
+    #[test]
+    fn succeeds_once_the_clock_recovers_within_budget() {
+        let uuid = recover_with_budget(3).expect("the fourth attempt must succeed");
+
+        assert_eq!(uuid.get_version(), Some(6));
+        assert_eq!(
+            uuid.get_timestamp(),
+            Some(UNIX_EPOCH + Duration::from_secs(1_700_000_001))
+        );
+    }
+
+    #[test]
+    fn gives_up_after_the_budget() {
+        assert_eq!(
+            recover_with_budget(2),
+            Err(UuidConstructionError::RateExceeded)
+        );
+    }
+
+    #[test]
+    fn overflow_is_not_retried() {
+        let mut attempts = 0;
+
+        let result: Result<(), _> = retry(10, || {
+            attempts += 1;
+
+            Err(UuidConstructionError::TimestampOverflow)
+        });
+
+        assert_eq!(result, Err(UuidConstructionError::TimestampOverflow));
+        assert_eq!(attempts, 1);
+    }
+
+    #[test]
+    fn gen_v6_retry_produces_v6() {
+        let uuid = UUID::gen_v6_retry(3).expect("generation must succeed");
+
+        assert_eq!(uuid.get_version(), Some(6));
+    }
+}
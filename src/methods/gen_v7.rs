@@ -16,6 +16,16 @@ impl UUID {
     /// 5. `UUID::new_v7` assembles the final UUID and patches
     ///    version & variant bits, so 62 of the random bits survive.
     ///
+    /// # Sub-millisecond precision
+    ///
+    /// The timestamp keeps its full nanosecond resolution up to step 5,
+    /// where `rand_a` receives the sub-millisecond fraction scaled to 4096
+    /// steps (RFC 9562 §6.2 Method 3) rather than random bits. Because
+    /// `STATE.next_v7` advances by at least 256 ns — more than one 244 ns
+    /// step — UUIDs issued within the same millisecond carry strictly
+    /// increasing fractions and therefore sort in issue order without a
+    /// counter in `rand_b`.
+    ///
     /// # Errors
     /// - `TimestampBeforeEpoch` is returned while the issued timestamp
     ///   precedes 1970-01-01, i.e. until a reading at or after the Unix epoch
@@ -23,6 +33,9 @@ impl UUID {
     ///
     /// Adoption is capped at the 60-bit RFC 4122 range (through 5236-03-31),
     /// so the 48-bit millisecond field cannot overflow from clock input.
+    ///
+    /// See [`UUID::gen_v7_monotonic`] for a variant that issues timestamps
+    /// without taking the [`STATE`] lock.
     pub fn gen_v7() -> Result<Self, UuidConstructionError> {
         // 1 — obtain monotonic timestamp
         let timestamp = {
@@ -32,22 +45,16 @@ impl UUID {
             ts
         };
 
-        // 2 — convert to Duration and validate range
+        // 2 — convert to Duration
         let duration = timestamp
             .duration_since(UNIX_EPOCH)
             .map_err(|_| UuidConstructionError::TimestampBeforeEpoch)?;
 
-        #[allow(clippy::items_after_statements)]
-        const MAX_MILLIS: u128 = 1u128 << 48; // 2⁴⁸ ms
-        if duration.as_millis() >= MAX_MILLIS {
-            return Err(UuidConstructionError::TimestampOverflow);
-        }
-
         // 3 — 64 bits (8 bytes) of randomness
         let random_bytes: [u8; 8] = random();
 
-        // 4 — assemble
-        Ok(Self::new_v7(duration, random_bytes))
+        // 4 — assemble, rejecting a timestamp beyond the 48-bit field
+        Self::new_v7_checked(duration, random_bytes)
     }
 }
 
@@ -76,6 +83,35 @@ mod tests {
         assert_eq!(variant(bytes), 0b10);
     }
 
+    #[test]
+    fn same_millisecond_order_follows_the_fraction() {
+        let ids: Vec<UUID> = (0..10_000)
+            .map(|_| UUID::gen_v7().expect("generation must succeed"))
+            .collect();
+
+        let mut same_ms = 0;
+
+        for pair in ids.windows(2) {
+            assert!(pair[0] < pair[1], "issue order must be sort order");
+
+            let [a, b] = [pair[0], pair[1]].map(|id| {
+                let bytes = id.as_bytes();
+
+                (
+                    id.v7_unix_millis(),
+                    u16::from_be_bytes([bytes[6], bytes[7]]) & 0x0FFF,
+                )
+            });
+
+            if a.0 == b.0 {
+                same_ms += 1;
+                assert!(a.1 < b.1, "rand_a must carry the increasing fraction");
+            }
+        }
+
+        assert!(same_ms > 0, "expected several UUIDs per millisecond");
+    }
+
     #[test]
     fn gen_v7_uniqueness_single_thread() {
         const N: usize = 10_000;
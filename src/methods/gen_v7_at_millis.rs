@@ -0,0 +1,98 @@
+use rand::RngCore;
+
+use crate::UUID;
+
+impl UUID {
+    /// Generates a Version-7 UUID for an exact Unix millisecond, drawing the
+    /// 74 random bits from `rng` instead of the thread-local generator.
+    ///
+    /// Both RFC 9562 random fields are drawn from `rng`: 12 bits for
+    /// `rand_a` and 62 for `rand_b`, assembled by [`UUID::from_parts_v7`].
+    /// Unlike [`UUID::new_v7`], no sub-millisecond fraction is encoded.
+    ///
+    /// Neither the system clock nor [`STATE`](crate::STATE) is consulted, so
+    /// a seeded `rng` makes the result reproducible, which suits tests and
+    /// deterministic simulations. Only the least-significant 48 bits of
+    /// `ms` are encoded, as in [`UUID::from_parts_v7`].
+    #[must_use]
+    pub fn gen_v7_at_millis<R: RngCore + ?Sized>(ms: u64, rng: &mut R) -> Self {
+        let mut random = [0u8; 10];
+
+        rng.fill_bytes(&mut random);
+
+        let [a0, a1, b0, b1, b2, b3, b4, b5, b6, b7] = random;
+
+        Self::from_parts_v7(
+            ms,
+            u16::from_be_bytes([a0, a1]),
+            u64::from_be_bytes([b0, b1, b2, b3, b4, b5, b6, b7]),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use rand::{rngs::StdRng, RngCore, SeedableRng};
+
+    use crate::{Variant, UUID};
+
+    #[test]
+    fn timestamp_is_the_requested_millisecond() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let ms = 1_700_000_000_123;
+
+        let uuid = UUID::gen_v7_at_millis(ms, &mut rng);
+
+        assert_eq!(uuid.get_version(), Some(7));
+        assert_eq!(uuid.get_variant(), Variant::OSF);
+        assert_eq!(
+            uuid.get_timestamp(),
+            Some(UNIX_EPOCH + Duration::from_millis(ms))
+        );
+    }
+
+    #[test]
+    fn seeded_rng_is_reproducible() {
+        let mut rng1 = StdRng::seed_from_u64(1);
+        let mut rng2 = StdRng::seed_from_u64(1);
+
+        for ms in [0, 1, 1_700_000_000_000, (1 << 48) - 1] {
+            assert_eq!(
+                UUID::gen_v7_at_millis(ms, &mut rng1),
+                UUID::gen_v7_at_millis(ms, &mut rng2)
+            );
+        }
+    }
+
+    #[test]
+    fn random_bits_vary() {
+        let mut rng = StdRng::seed_from_u64(2);
+
+        let first = UUID::gen_v7_at_millis(0, &mut rng);
+        let second = UUID::gen_v7_at_millis(0, &mut rng);
+
+        assert_ne!(first, second);
+    }
+
+    #[test]
+    fn rand_fields_come_from_the_rng() {
+        let mut random = [0u8; 10];
+
+        StdRng::seed_from_u64(3).fill_bytes(&mut random);
+
+        let [a0, a1, b0, b1, b2, b3, b4, b5, b6, b7] = random;
+        let expected = UUID::from_parts_v7(
+            42,
+            u16::from_be_bytes([a0, a1]),
+            u64::from_be_bytes([b0, b1, b2, b3, b4, b5, b6, b7]),
+        );
+
+        assert_eq!(
+            UUID::gen_v7_at_millis(42, &mut StdRng::seed_from_u64(3)),
+            expected
+        );
+        assert_eq!(expected.v7_unix_millis(), Some(42));
+    }
+}
@@ -0,0 +1,123 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use rand::{Rng, RngCore};
+
+use crate::{UuidConstructionError, UUID};
+
+/// The first millisecond count the 48-bit `unix_ts_ms` field cannot hold.
+const MAX_MILLIS: u128 = 1 << 48;
+
+/// Returns the first whole Unix millisecond at or after `time`.
+fn ceil_millis(time: SystemTime) -> Result<u128, UuidConstructionError> {
+    let since_unix = time
+        .duration_since(UNIX_EPOCH)
+        .map_err(|_| UuidConstructionError::TimestampBeforeEpoch)?;
+
+    Ok(since_unix.as_millis() + u128::from(since_unix.subsec_nanos() % 1_000_000 != 0))
+}
+
+impl UUID {
+    /// Generates a Version-7 UUID for a random whole millisecond within
+    /// `[start, end)`, drawing the millisecond and the 74 random bits from
+    /// `rng`.
+    ///
+    /// Suited to fixtures and load tests that need time-ordered IDs spread
+    /// over a window; see [`UUID::gen_v7_at_millis`], which this builds on.
+    /// The decoded [`UUID::get_timestamp`] always lies within the window.
+    ///
+    /// # Errors
+    /// - `TimestampBeforeEpoch` is returned if `start` or `end` precedes
+    ///   1970-01-01.
+    /// - `TimestampOverflow` is returned if the window reaches beyond the
+    ///   48-bit millisecond range (≈ 10889-08-02).
+    /// - `FieldOutOfRange("end")` is returned if the window contains no whole
+    ///   millisecond, e.g. because `end` is not after `start`.
+    pub fn gen_v7_in_range<R: RngCore + ?Sized>(
+        start: SystemTime,
+        end: SystemTime,
+        rng: &mut R,
+    ) -> Result<Self, UuidConstructionError> {
+        let (first, last) = (ceil_millis(start)?, ceil_millis(end)?);
+
+        if last > MAX_MILLIS {
+            return Err(UuidConstructionError::TimestampOverflow);
+        }
+
+        if first >= last {
+            return Err(UuidConstructionError::FieldOutOfRange("end"));
+        }
+
+        // Both bounds are at most 2⁴⁸, so they fit a u64.
+        #[allow(clippy::cast_possible_truncation)]
+        let ms = rng.random_range(first as u64..last as u64);
+
+        Ok(Self::gen_v7_at_millis(ms, rng))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use rand::{rngs::StdRng, SeedableRng};
+
+    use crate::{UuidConstructionError, UUID};
+
+    #[test]
+    fn timestamps_fall_within_the_window() {
+        let mut rng = StdRng::seed_from_u64(0x5EED);
+        let start = UNIX_EPOCH + Duration::from_micros(1_700_000_000_000_500);
+        let end = start + Duration::from_secs(60);
+
+        for _ in 0..1000 {
+            let uuid = UUID::gen_v7_in_range(start, end, &mut rng).expect("valid window");
+            let t = uuid.get_timestamp().expect("v7 carries a timestamp");
+
+            assert_eq!(uuid.get_version(), Some(7));
+            assert!(start <= t && t < end, "{t:?} outside the window");
+        }
+    }
+
+    #[test]
+    fn single_millisecond_window_is_exact() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let start = UNIX_EPOCH + Duration::from_secs(1);
+
+        let uuid = UUID::gen_v7_in_range(start, start + Duration::from_millis(1), &mut rng)
+            .expect("valid window");
+
+        assert_eq!(uuid.v7_unix_millis(), Some(1000));
+    }
+
+    #[test]
+    fn invalid_windows_are_rejected() {
+        let mut rng = StdRng::seed_from_u64(2);
+        let t = UNIX_EPOCH + Duration::from_secs(1);
+
+        assert_eq!(
+            UUID::gen_v7_in_range(t, t, &mut rng),
+            Err(UuidConstructionError::FieldOutOfRange("end"))
+        );
+        assert_eq!(
+            UUID::gen_v7_in_range(
+                t + Duration::from_nanos(1),
+                t + Duration::from_micros(999),
+                &mut rng
+            ),
+            Err(UuidConstructionError::FieldOutOfRange("end"))
+        );
+        assert_eq!(
+            UUID::gen_v7_in_range(UNIX_EPOCH - Duration::from_secs(1), t, &mut rng),
+            Err(UuidConstructionError::TimestampBeforeEpoch)
+        );
+        assert_eq!(
+            UUID::gen_v7_in_range(
+                t,
+                UNIX_EPOCH + Duration::from_millis((1 << 48) + 1),
+                &mut rng
+            ),
+            Err(UuidConstructionError::TimestampOverflow)
+        );
+    }
+}
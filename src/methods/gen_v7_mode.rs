@@ -0,0 +1,124 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use rand::random;
+
+use crate::{UuidConstructionError, STATE, UUID};
+
+/// The first millisecond count the 48-bit `unix_ts_ms` field cannot hold.
+const MAX_MILLIS: u64 = 1 << 48;
+
+/// How [`UUID::gen_v7_mode`] fills the 12-bit `rand_a` field of a
+/// version-7 UUID (RFC 9562 §6.2).
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq)]
+pub enum V7Mode {
+    /// `rand_a` is random, so UUIDs within one millisecond are unordered.
+    Random,
+
+    /// `rand_a` is a counter that restarts at zero each millisecond and
+    /// increments per UUID (Method 1), so UUIDs within one millisecond sort
+    /// in issue order while revealing only their count.
+    Counter,
+
+    /// `rand_a` is the sub-millisecond fraction of the timestamp scaled to
+    /// 4096 steps (Method 3), as [`UUID::gen_v7`] encodes it.
+    #[default]
+    SubMs,
+}
+
+impl UUID {
+    /// Generate a **Version 7** UUID whose `rand_a` field is filled
+    /// according to `mode`.
+    ///
+    /// Every mode draws its timestamp from the same strictly increasing
+    /// sequence in [`STATE`] as [`UUID::gen_v7`], so UUIDs from different
+    /// modes still order by millisecond relative to each other; `rand_b`
+    /// always holds 62 random bits. `gen_v7_mode(V7Mode::SubMs)` is
+    /// equivalent to [`UUID::gen_v7`].
+    ///
+    /// # Errors
+    /// - `TimestampBeforeEpoch` is returned while the issued timestamp
+    ///   precedes 1970-01-01.
+    /// - `TimestampOverflow` is returned if the issued timestamp does not fit
+    ///   the 48-bit millisecond field.
+    pub fn gen_v7_mode(mode: V7Mode) -> Result<Self, UuidConstructionError> {
+        let mut guard = STATE.lock();
+
+        let since_unix = guard
+            .next_v7(SystemTime::now())
+            .duration_since(UNIX_EPOCH)
+            .map_err(|_| UuidConstructionError::TimestampBeforeEpoch)?;
+
+        let ms = u64::try_from(since_unix.as_millis())
+            .ok()
+            .filter(|&ms| ms < MAX_MILLIS)
+            .ok_or(UuidConstructionError::TimestampOverflow)?;
+
+        let rand_a = match mode {
+            V7Mode::Random => random(),
+            V7Mode::Counter => guard.next_v7_counter(ms),
+            V7Mode::SubMs => {
+                drop(guard);
+
+                return Self::new_v7_checked(since_unix, random());
+            }
+        };
+
+        drop(guard);
+
+        Ok(Self::from_parts_v7(ms, rand_a, random()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+    use super::V7Mode;
+    use crate::{Variant, UUID};
+
+    const MODES: [V7Mode; 3] = [V7Mode::Random, V7Mode::Counter, V7Mode::SubMs];
+
+    fn rand_a(uuid: UUID) -> u16 {
+        u16::from_be_bytes([uuid.as_bytes()[6], uuid.as_bytes()[7]]) & 0x0FFF
+    }
+
+    #[test]
+    fn every_mode_yields_v7_osf() {
+        for mode in MODES {
+            let uuid = UUID::gen_v7_mode(mode).expect("generation must succeed");
+
+            assert_eq!(uuid.get_version(), Some(7), "{mode:?}");
+            assert_eq!(uuid.get_variant(), Variant::OSF, "{mode:?}");
+        }
+    }
+
+    #[test]
+    fn counter_is_strictly_monotonic_within_a_millisecond() {
+        let ids: Vec<UUID> = (0..10_000)
+            .map(|_| UUID::gen_v7_mode(V7Mode::Counter).expect("generation must succeed"))
+            .collect();
+
+        let mut same_ms = 0;
+
+        for pair in ids.windows(2) {
+            assert!(pair[0] < pair[1], "issue order must be sort order");
+
+            if pair[0].v7_unix_millis() == pair[1].v7_unix_millis() {
+                same_ms += 1;
+                assert!(rand_a(pair[0]) < rand_a(pair[1]));
+            }
+        }
+
+        assert!(same_ms > 0, "expected several UUIDs per millisecond");
+    }
+
+    #[test]
+    fn modes_share_the_timestamp_sequence() {
+        let ids: Vec<UUID> = (0..300)
+            .map(|i| UUID::gen_v7_mode(MODES[i % 3]).expect("generation must succeed"))
+            .collect();
+
+        assert!(ids
+            .windows(2)
+            .all(|pair| pair[0].v7_unix_millis() <= pair[1].v7_unix_millis()));
+    }
+}
@@ -0,0 +1,312 @@
+use std::{
+    sync::atomic::{AtomicU64, Ordering},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use rand::random;
+
+use crate::{UuidConstructionError, UUID};
+
+/// The most recently issued version-7 timestamp, packed as
+/// `unix_ts_ms << 12 | fraction`, where `fraction` is the sub-millisecond
+/// part scaled to 4096 steps exactly as [`UUID::new_v7`] encodes it.
+static LAST_V7: AtomicU64 = AtomicU64::new(0);
+
+/// Clears [`LAST_V7`], so the next [`UUID::gen_v7_monotonic`] adopts the
+/// clock reading even if an earlier call ran the sequence ahead of it.
+pub(crate) fn reset_v7_monotonic() {
+    LAST_V7.store(0, Ordering::Relaxed);
+}
+
+/// The first packed value the 48-bit millisecond field cannot hold.
+const PACKED_LIMIT: u64 = 1 << 60;
+
+/// Packs a clock reading, or returns `None` if it precedes the Unix epoch or
+/// exceeds the 48-bit millisecond range.
+fn pack(timestamp: SystemTime) -> Option<u64> {
+    let since_unix = timestamp.duration_since(UNIX_EPOCH).ok()?;
+    let ms = u64::try_from(since_unix.as_millis()).ok()?;
+    let fraction = u64::from(since_unix.subsec_nanos() % 1_000_000) * 4096 / 1_000_000;
+
+    let packed = ms.checked_mul(4096)? | fraction;
+
+    (packed < PACKED_LIMIT).then_some(packed)
+}
+
+/// Packs the timestamp bits of `floor` so that every UUID issued from a
+/// strictly greater packed value compares greater than `floor`.
+const fn pack_floor(floor: &UUID) -> u64 {
+    let [b0, b1, b2, b3, b4, b5, b6, b7, ..] = *floor.as_bytes();
+    let high = u64::from_be_bytes([b0, b1, b2, b3, b4, b5, b6, b7]);
+
+    let ms = high >> 16;
+
+    // A version nibble above 7 outranks every fraction in the same
+    // millisecond, so the next millisecond is the first one above `floor`.
+    let fraction = if (high >> 12) & 0xF > 7 {
+        0x0FFF
+    } else {
+        high & 0x0FFF
+    };
+
+    ms << 12 | fraction
+}
+
+/// Issues the next packed timestamp from `last` with a single CAS loop: the
+/// clock reading if it is ahead of both the last issued value and `floor`,
+/// else the greater of the two plus one step (≈244 ns).
+fn next_packed(
+    last: &AtomicU64,
+    timestamp: SystemTime,
+    floor: u64,
+) -> Result<u64, UuidConstructionError> {
+    let reading = pack(timestamp);
+
+    let update = |previous: u64| {
+        let advanced = previous.max(floor) + 1;
+
+        let next = match reading {
+            Some(reading) if reading > advanced => reading,
+            _ => advanced,
+        };
+
+        (next < PACKED_LIMIT).then_some(next)
+    };
+
+    let previous = last
+        .fetch_update(Ordering::Relaxed, Ordering::Relaxed, update)
+        .map_err(|_| UuidConstructionError::TimestampOverflow)?;
+
+    // `update` is deterministic in `previous`, so recomputing it yields the
+    // value that was stored.
+    update(previous).ok_or(UuidConstructionError::TimestampOverflow)
+}
+
+impl UUID {
+    /// Generate an RFC-4122 **Version 7** UUID without taking the [`STATE`]
+    /// lock.
+    ///
+    /// The 60 timestamp bits (48-bit milliseconds plus the 12-bit
+    /// sub-millisecond fraction of RFC 9562 §6.2 Method 3) are issued from a
+    /// dedicated `AtomicU64` updated by a compare-and-swap loop, so
+    /// concurrent callers never serialize on a mutex. Each call issues a
+    /// strictly greater timestamp than every earlier call since the last
+    /// [`UUID::reset_global_state`]: the current clock reading if it is
+    /// ahead, else the last issued value advanced by one step. A clock reading before 1970 or beyond the 48-bit millisecond
+    /// range is never adopted.
+    ///
+    /// This sequence is independent of the one [`UUID::gen_v7`] draws from
+    /// [`STATE`], so UUIDs from the two generators are not ordered relative
+    /// to each other; the remaining 62 random bits keep them distinct.
+    ///
+    /// [`STATE`]: crate::STATE
+    ///
+    /// # Errors
+    /// - `TimestampOverflow` is returned once the issued timestamps have
+    ///   exhausted the 48-bit millisecond range (≈ 10889-08-02).
+    pub fn gen_v7_monotonic() -> Result<Self, UuidConstructionError> {
+        Self::gen_v7_monotonic_since(&Self::nil())
+    }
+
+    /// Generate a **Version 7** UUID from the same lock-free sequence as
+    /// [`UUID::gen_v7_monotonic`], additionally guaranteed to compare greater
+    /// than `floor`.
+    ///
+    /// Passing the last persisted UUID of a log keeps the stream strictly
+    /// increasing across process restarts, even if the clock has stepped
+    /// backwards or `floor` lies in the future: the timestamp is advanced one
+    /// step past the timestamp bits of `floor` whenever the clock has not
+    /// already moved beyond them. The issued timestamp also raises the
+    /// process-wide sequence, so later calls to either function stay above
+    /// `floor`.
+    ///
+    /// # Errors
+    /// - `TimestampOverflow` is returned if no timestamp above both `floor`
+    ///   and the last issued value fits the 48-bit millisecond range.
+    pub fn gen_v7_monotonic_since(floor: &Self) -> Result<Self, UuidConstructionError> {
+        let packed = next_packed(&LAST_V7, SystemTime::now(), pack_floor(floor))?;
+
+        #[allow(clippy::cast_possible_truncation)]
+        Ok(Self::from_parts_v7(
+            packed >> 12,
+            (packed & 0x0FFF) as u16,
+            random(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+    use std::{
+        collections::HashSet,
+        sync::{atomic::AtomicU64, Arc},
+        thread,
+        time::{Duration, SystemTime, UNIX_EPOCH},
+    };
+
+    use super::{next_packed, pack, pack_floor, PACKED_LIMIT};
+    use crate::{UuidConstructionError, Variant, UUID};
+
+    #[test]
+    fn packing_matches_new_v7() {
+        let since_unix = Duration::from_millis(1_700_000_000_123) + Duration::from_nanos(987_654);
+        let packed = pack(UNIX_EPOCH + since_unix).expect("must be packable");
+        let uuid = UUID::new_v7(since_unix, [0; 8]);
+
+        let encoded = u64::from_be_bytes([
+            0,
+            0,
+            uuid.as_bytes()[0],
+            uuid.as_bytes()[1],
+            uuid.as_bytes()[2],
+            uuid.as_bytes()[3],
+            uuid.as_bytes()[4],
+            uuid.as_bytes()[5],
+        ]);
+        let fraction = u16::from_be_bytes([uuid.as_bytes()[6], uuid.as_bytes()[7]]) & 0x0FFF;
+
+        assert_eq!(packed >> 12, encoded);
+        assert_eq!(packed & 0x0FFF, u64::from(fraction));
+    }
+
+    #[test]
+    fn gen_v7_monotonic_produces_valid_uuids() {
+        let uuid = UUID::gen_v7_monotonic().expect("generation must succeed");
+
+        assert_eq!(uuid.get_version(), Some(7));
+        assert_eq!(uuid.get_variant(), Variant::OSF);
+    }
+
+    #[test]
+    fn frozen_clock_self_advances() {
+        let last = AtomicU64::new(0);
+        let frozen = UNIX_EPOCH + Duration::from_secs(1_000_000_000);
+
+        let first = next_packed(&last, frozen, 0).expect("must issue");
+        let second = next_packed(&last, frozen, 0).expect("must issue");
+
+        assert_eq!(first, pack(frozen).expect("must be packable"));
+        assert_eq!(second, first + 1);
+    }
+
+    #[test]
+    fn unrepresentable_readings_are_not_adopted() {
+        let last = AtomicU64::new(41);
+
+        let before_unix = UNIX_EPOCH - Duration::from_secs(1);
+        let beyond = UNIX_EPOCH + Duration::from_millis(1 << 48);
+
+        assert_eq!(next_packed(&last, before_unix, 0), Ok(42));
+        assert_eq!(next_packed(&last, beyond, 0), Ok(43));
+    }
+
+    #[test]
+    fn exhaustion_reports_overflow() {
+        let last = AtomicU64::new(PACKED_LIMIT - 1);
+
+        assert_eq!(
+            next_packed(&last, SystemTime::now(), 0),
+            Err(UuidConstructionError::TimestampOverflow)
+        );
+    }
+
+    #[test]
+    fn concurrent_issues_are_strictly_increasing_and_unique() {
+        const THREADS: usize = 8;
+        const PER_THREAD: usize = 20_000;
+
+        let last = Arc::new(AtomicU64::new(0));
+
+        let handles: Vec<_> = (0..THREADS)
+            .map(|_| {
+                let last = Arc::clone(&last);
+
+                thread::spawn(move || {
+                    let mut issued = Vec::with_capacity(PER_THREAD);
+
+                    for _ in 0..PER_THREAD {
+                        issued.push(next_packed(&last, SystemTime::now(), 0).expect("must issue"));
+                    }
+
+                    issued
+                })
+            })
+            .collect();
+
+        let mut all = HashSet::with_capacity(THREADS * PER_THREAD);
+
+        for handle in handles {
+            let issued = handle.join().expect("thread panicked");
+
+            assert!(
+                issued.windows(2).all(|pair| pair[0] < pair[1]),
+                "Each thread must observe strictly increasing (ms, fraction) pairs."
+            );
+
+            for packed in issued {
+                assert!(all.insert(packed), "No packed timestamp may repeat.");
+            }
+        }
+    }
+
+    #[test]
+    fn output_exceeds_a_future_floor() {
+        let future = SystemTime::now() + Duration::from_secs(60);
+        let floor = UUID::new_v7(
+            future.duration_since(UNIX_EPOCH).expect("after the epoch"),
+            [0xFF; 8],
+        );
+
+        let uuid = UUID::gen_v7_monotonic_since(&floor).expect("generation must succeed");
+
+        assert!(uuid > floor);
+        assert_eq!(uuid.get_version(), Some(7));
+
+        // The clock has not caught up, so the floor is advanced by one step.
+        let last = AtomicU64::new(0);
+
+        assert_eq!(
+            next_packed(&last, SystemTime::now(), pack_floor(&floor)),
+            Ok(pack_floor(&floor) + 1)
+        );
+    }
+
+    #[test]
+    fn floor_is_exceeded_whatever_its_version() {
+        for floor in [
+            UUID::nil(),
+            UUID::max(),
+            UUID::from_parts_v7(1_000, 0x0FFF, u64::MAX).with_version(8),
+            UUID::from_parts_v7(1_000, 0x0FFF, u64::MAX).with_version(6),
+            UUID::from_parts_v7(1_000, 0x0FFF, u64::MAX),
+        ] {
+            let last = AtomicU64::new(0);
+
+            match next_packed(&last, UNIX_EPOCH, pack_floor(&floor)) {
+                #[allow(clippy::cast_possible_truncation)]
+                Ok(packed) => {
+                    let uuid = UUID::from_parts_v7(packed >> 12, (packed & 0x0FFF) as u16, 0);
+
+                    assert!(uuid > floor, "{uuid} must exceed {floor}");
+                }
+                Err(err) => {
+                    assert!(floor.is_max(), "only MAX may be unreachable: {floor}");
+                    assert_eq!(err, UuidConstructionError::TimestampOverflow);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn floor_below_the_clock_is_ignored() {
+        let last = AtomicU64::new(0);
+        let now = UNIX_EPOCH + Duration::from_secs(1_000_000_000);
+        let floor = UUID::from_parts_v7(1_000, 0, 0);
+
+        assert_eq!(
+            next_packed(&last, now, pack_floor(&floor)),
+            Ok(pack(now).expect("must be packable"))
+        );
+    }
+}
@@ -0,0 +1,51 @@
+use crate::{Variant, UUID};
+
+impl UUID {
+    /// Returns the 7-byte host address (bytes 9‥=15) of an NCS variant UUID,
+    /// the inverse of the `address` argument of [`UUID::new_ncs`] and
+    /// [`UUID::from_parts_ncs`]. Wrap it in [`NcsAddress`](crate::NcsAddress)
+    /// for a typed address.
+    ///
+    /// Returns `None` if the UUID is not of the [`Variant::NCS`] variant.
+    #[must_use]
+    pub const fn get_ncs_address(&self) -> Option<[u8; 7]> {
+        if !matches!(self.get_variant(), Variant::NCS) {
+            return None;
+        }
+
+        let [.., a0, a1, a2, a3, a4, a5, a6] = self.bytes;
+
+        Some([a0, a1, a2, a3, a4, a5, a6])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use crate::UUID;
+
+    const ADDRESS: [u8; 7] = [0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0xFF];
+
+    #[test]
+    fn round_trips_new_ncs() {
+        let time = UNIX_EPOCH + Duration::from_secs(315_532_800 + 3600);
+        let uuid = UUID::new_ncs(time, 13, ADDRESS).expect("must construct");
+
+        assert_eq!(uuid.get_ncs_address(), Some(ADDRESS));
+    }
+
+    #[test]
+    fn round_trips_from_parts_ncs() {
+        let uuid = UUID::from_parts_ncs(&[0; 6], 2, &ADDRESS);
+
+        assert_eq!(uuid.get_ncs_address(), Some(ADDRESS));
+    }
+
+    #[test]
+    fn non_ncs_yields_none() {
+        assert_eq!(UUID::gen_v4().get_ncs_address(), None);
+        assert_eq!(UUID::max().get_ncs_address(), None);
+    }
+}
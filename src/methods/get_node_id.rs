@@ -4,7 +4,15 @@ impl UUID {
     /// Returns the node identifier, or `None` if the UUID does not carry one.
     ///
     /// Versions 1, 2, and 6 and UUIDs of the DCOM variant carry a node
-    /// identifier. The DCOM interpretation is this crate's own (see
+    /// identifier.
+    ///
+    /// In a version-2 UUID the node identifier is intact: the local ID and
+    /// domain only overwrite `time_low` and `clock_seq_low`, so of the three
+    /// v1 fields it inherits, only the node survives in full, while
+    /// [`UUID::get_timestamp`] and [`UUID::get_clock_seq`] recover just the
+    /// bits that remain.
+    ///
+    /// The DCOM interpretation is this crate's own (see
     /// [`UUID::new_dcom`]); for Microsoft GUIDs from other sources, such as
     /// COM interface identifiers, the returned bytes are not a node
     /// identifier.
@@ -127,4 +135,21 @@ mod tests {
             })
         );
     }
+
+    #[test]
+    fn v2_node_id_survives_the_domain_and_local_id() {
+        let node = [0x00, 0x1B, 0x63, 0x84, 0x45, 0xE6];
+        let time = std::time::UNIX_EPOCH + std::time::Duration::from_secs(1_700_000_000);
+
+        let v1 = UUID::new_v1(time, 0x3FFF, node).expect("new_v1 should succeed");
+        let v2 = UUID::new_v2(0xFF, u32::MAX, time, 0x3FFF, node).expect("new_v2 should succeed");
+
+        assert_eq!(v2.get_node_id(), Some(NodeId { bytes: node }));
+        assert_eq!(v2.get_node_id(), v1.get_node_id());
+
+        // The fields the domain and local ID overwrite are only partially
+        // recovered.
+        assert_ne!(v2.get_timestamp(), v1.get_timestamp());
+        assert_ne!(v2.get_clock_seq(), v1.get_clock_seq());
+    }
 }
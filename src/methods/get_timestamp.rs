@@ -211,7 +211,7 @@ mod tests {
         let t = ncs_epoch + Duration::from_secs(1_000_000);
         let address = [0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07];
         let uuid =
-            UUID::new_ncs(t, 1, &address).expect("new_ncs should succeed for valid test inputs");
+            UUID::new_ncs(t, 1, address).expect("new_ncs should succeed for valid test inputs");
         let ts = uuid
             .get_timestamp()
             .expect("timestamp should be present for time-based UUID");
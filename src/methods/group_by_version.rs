@@ -0,0 +1,63 @@
+use crate::UUID;
+
+impl UUID {
+    /// Counts how many of `ids` carry each version, indexed by version
+    /// number.
+    ///
+    /// UUIDs that are not of the OSF variant have no version and are counted
+    /// in bucket 0, which no RFC 4122 UUID uses.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ps_uuid::UUID;
+    ///
+    /// let counts = UUID::group_by_version(&[UUID::gen_v4(), UUID::gen_v4(), UUID::nil()]);
+    ///
+    /// assert_eq!(counts[4], 2);
+    /// assert_eq!(counts[0], 1);
+    /// ```
+    #[must_use]
+    pub fn group_by_version(ids: &[Self]) -> [usize; 16] {
+        let mut counts = [0; 16];
+
+        for id in ids {
+            counts[usize::from(id.version_or(0))] += 1;
+        }
+
+        counts
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+    use crate::UUID;
+
+    #[test]
+    fn counts_a_mixed_slice() {
+        let ids = [
+            UUID::gen_v1().expect("v1 generation"),
+            UUID::gen_v4(),
+            UUID::gen_v4(),
+            UUID::gen_v7().expect("v7 generation"),
+            UUID::gen_v7().expect("v7 generation"),
+            UUID::gen_v7().expect("v7 generation"),
+            UUID::gen_dcom([1, 2, 3, 4, 5, 6]).expect("DCOM generation"),
+        ];
+
+        let mut expected = [0; 16];
+
+        expected[0] = 1;
+        expected[1] = 1;
+        expected[4] = 2;
+        expected[7] = 3;
+
+        assert_eq!(UUID::group_by_version(&ids), expected);
+    }
+
+    #[test]
+    fn empty_slice_counts_nothing() {
+        assert_eq!(UUID::group_by_version(&[]), [0; 16]);
+    }
+}
@@ -0,0 +1,61 @@
+use crate::UUID;
+
+impl UUID {
+    /// Returns the number of bits in which the two UUIDs differ.
+    ///
+    /// Two independent v4 UUIDs differ in about 61 of their 122 random bits,
+    /// with a standard deviation of about 5.5. A distance far below that
+    /// between UUIDs meant to be random points to a stuck or low-entropy
+    /// RNG.
+    #[must_use]
+    pub const fn hamming_distance(&self, other: &Self) -> u32 {
+        (self.to_u128() ^ other.to_u128()).count_ones()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::UUID;
+
+    #[test]
+    fn distance_to_self_is_zero() {
+        let uuid = UUID::gen_v4();
+
+        assert_eq!(uuid.hamming_distance(&uuid), 0);
+    }
+
+    #[test]
+    fn distance_to_complement_is_128() {
+        let uuid = UUID::gen_v4();
+
+        assert_eq!(uuid.hamming_distance(&!uuid), 128);
+        assert_eq!(UUID::nil().hamming_distance(&UUID::max()), 128);
+    }
+
+    #[test]
+    fn is_symmetric_and_counts_single_bits() {
+        let a = UUID::NS_DNS;
+        let b = a ^ UUID::from_u128(0b1011 << 64);
+
+        assert_eq!(a.hamming_distance(&b), 3);
+        assert_eq!(b.hamming_distance(&a), 3);
+    }
+
+    #[test]
+    fn random_v4_uuids_are_far_apart() {
+        // The version and variant bits always agree, so at most 122 differ;
+        // 30 is more than five standard deviations below the mean.
+        for _ in 0..1000 {
+            let distance = UUID::gen_v4().hamming_distance(&UUID::gen_v4());
+
+            assert!((30..=122).contains(&distance), "{distance}");
+        }
+    }
+
+    #[test]
+    fn const_context() {
+        const DISTANCE: u32 = UUID::NS_DNS.hamming_distance(&UUID::NS_URL);
+
+        assert_eq!(DISTANCE, 1);
+    }
+}
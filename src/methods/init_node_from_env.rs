@@ -0,0 +1,90 @@
+use crate::{NodeId, NodeIdParseError, State, STATE, UUID};
+
+/// Installs the node ID held in the environment variable `var` into `state`,
+/// leaving `state` unchanged on error.
+fn install_node_from_env(state: &mut State, var: &str) -> Result<(), NodeIdParseError> {
+    let value = std::env::var(var).map_err(|_| NodeIdParseError::MissingVar)?;
+    let node_id: NodeId = value.parse()?;
+
+    state.set_node_id(node_id);
+
+    Ok(())
+}
+
+impl UUID {
+    /// Pins the node ID of the process-wide [`STATE`] to the MAC address in
+    /// the environment variable `var`.
+    ///
+    /// Version 1 and 6 UUIDs generated afterwards embed this node instead of
+    /// the one [`NodeId::acquire`] picked at startup, so operators can fix it
+    /// deterministically, e.g. to one derived from a pod ordinal. The value
+    /// may use any spelling [`NodeId`]'s `FromStr` accepts. On error,
+    /// [`STATE`] is left unchanged.
+    ///
+    /// # Errors
+    ///
+    /// - [`NodeIdParseError::MissingVar`] if `var` is unset or not valid
+    ///   Unicode.
+    /// - Any error [`NodeId`]'s `FromStr` returns for the value.
+    pub fn init_node_from_env(var: &str) -> Result<(), NodeIdParseError> {
+        install_node_from_env(&mut STATE.lock(), var)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+    use std::time::SystemTime;
+
+    use super::install_node_from_env;
+    use crate::{NodeId, NodeIdParseError, State, UUID};
+
+    #[test]
+    fn installed_node_is_embedded_in_v1_uuids() {
+        const VAR: &str = "PS_UUID_TEST_NODE_INSTALLED";
+
+        // A multicast node, as a randomly drawn one would be.
+        let node = NodeId::from([0x03, 0x11, 0x22, 0x33, 0x44, 0x55]);
+
+        std::env::set_var(VAR, "03:11:22:33:44:55");
+
+        // A private state, so concurrent resets of `STATE` cannot interfere.
+        let mut state = State::default();
+
+        install_node_from_env(&mut state, VAR).expect("the variable holds a valid MAC");
+
+        let (timestamp, clock_seq) = state.next(SystemTime::now()).expect("a tick is available");
+        let uuid =
+            UUID::new_v1(timestamp, clock_seq, *state.node_id()).expect("generation must succeed");
+
+        assert_eq!(state.node_id(), node);
+        assert_eq!(uuid.get_node_id(), Some(node));
+    }
+
+    #[test]
+    fn installs_into_the_global_state() {
+        const VAR: &str = "PS_UUID_TEST_NODE_GLOBAL";
+
+        std::env::set_var(VAR, "03:66:77:88:99:aa");
+
+        assert_eq!(UUID::init_node_from_env(VAR), Ok(()));
+    }
+
+    #[test]
+    fn missing_or_malformed_variables_are_rejected() {
+        const MISSING: &str = "PS_UUID_TEST_NODE_MISSING";
+        const MALFORMED: &str = "PS_UUID_TEST_NODE_MALFORMED";
+
+        std::env::remove_var(MISSING);
+        std::env::set_var(MALFORMED, "not-a-mac");
+
+        assert_eq!(
+            UUID::init_node_from_env(MISSING),
+            Err(NodeIdParseError::MissingVar)
+        );
+        assert_eq!(
+            UUID::init_node_from_env(MALFORMED),
+            Err(NodeIdParseError::InvalidLength)
+        );
+    }
+}
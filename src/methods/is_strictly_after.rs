@@ -0,0 +1,97 @@
+use crate::UUID;
+
+impl UUID {
+    /// Returns whether `self` sorts strictly after `prev` in generation
+    /// order, for asserting that a generator's output is monotonic.
+    ///
+    /// Versions 1 and 6 are compared by their [`UUID::logical_key`]:
+    /// timestamp, then clock sequence, then node. This orders version-1
+    /// UUIDs, whose bytes do not sort by time, as if converted to version 6.
+    /// All other UUIDs, including version 7, are compared byte-wise.
+    ///
+    /// The comparison is meaningful between UUIDs of one generator; mixing
+    /// time-based and other versions yields an arbitrary but consistent
+    /// answer.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ps_uuid::UUID;
+    ///
+    /// let first = UUID::gen_v7_monotonic().unwrap();
+    /// let second = UUID::gen_v7_monotonic().unwrap();
+    ///
+    /// assert!(second.is_strictly_after(&first));
+    /// assert!(!first.is_strictly_after(&first));
+    /// ```
+    #[must_use]
+    pub const fn is_strictly_after(&self, prev: &Self) -> bool {
+        u128::from_be_bytes(self.logical_key()) > u128::from_be_bytes(prev.logical_key())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use crate::UUID;
+
+    #[test]
+    fn gen_v7_monotonic_batches_are_strictly_increasing() {
+        let batch: Vec<UUID> = (0..10_000)
+            .map(|_| UUID::gen_v7_monotonic().expect("generation must succeed"))
+            .collect();
+
+        for pair in batch.windows(2) {
+            assert!(
+                pair[1].is_strictly_after(&pair[0]),
+                "{} <= {}",
+                pair[1],
+                pair[0]
+            );
+        }
+    }
+
+    #[test]
+    fn equal_uuids_are_not_after_each_other() {
+        let uuid = UUID::gen_v4();
+
+        assert!(!uuid.is_strictly_after(&uuid));
+        assert!(!UUID::NS_DNS.is_strictly_after(&UUID::NS_DNS));
+    }
+
+    #[test]
+    fn v1_is_ordered_by_timestamp_not_bytes() {
+        let node = [0xAA; 6];
+
+        // The later timestamp carries into `time_mid`, so its `time_low`
+        // bytes, which lead a version-1 UUID, wrap around to zero.
+        let earlier = UUID::new_v1(UNIX_EPOCH + Duration::from_secs(1_700_000_000), 0, node)
+            .expect("valid v1 inputs");
+        let [l0, l1, l2, l3, ..] = *earlier.as_bytes();
+        let until_wrap = (1 << 32) - u64::from(u32::from_be_bytes([l0, l1, l2, l3]));
+        let later = UUID::new_v1(
+            UNIX_EPOCH
+                + Duration::from_secs(1_700_000_000)
+                + Duration::from_nanos(until_wrap * 100),
+            0,
+            node,
+        )
+        .expect("valid v1 inputs");
+
+        assert!(later < earlier, "bytes must not sort by time here");
+        assert!(later.is_strictly_after(&earlier));
+        assert!(!earlier.is_strictly_after(&later));
+    }
+
+    #[test]
+    fn v1_ties_are_broken_by_clock_sequence() {
+        let time = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        let first = UUID::new_v1(time, 1, [0; 6]).expect("valid v1 inputs");
+        let second = UUID::new_v1(time, 2, [0; 6]).expect("valid v1 inputs");
+
+        assert!(second.is_strictly_after(&first));
+        assert!(!first.is_strictly_after(&second));
+    }
+}
@@ -0,0 +1,62 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::{STATE, UUID};
+
+impl UUID {
+    /// Returns the timestamp of the most recent tick issued from [`STATE`],
+    /// i.e. the one the last [`UUID::gen_v7`] call encoded.
+    ///
+    /// The generator advances its timestamp monotonically, so under bursts
+    /// or a clock that steps backward it can run ahead of
+    /// [`SystemTime::now`]; comparing the two lets a background task alarm
+    /// on that drift.
+    ///
+    /// [`STATE`] is shared by every time-based generator, so this also
+    /// reflects ticks issued by [`UUID::gen_v1`], [`UUID::gen_v6`] and
+    /// friends. [`UUID::gen_v7_monotonic`] draws from a separate sequence
+    /// and is not reported.
+    ///
+    /// Returns `None` while the last tick precedes the Unix epoch, which a
+    /// version-7 UUID cannot encode. Before any tick has been issued, the
+    /// value is the initial floor of [`STATE`]: it precedes the Unix epoch
+    /// on platforms whose clock can represent 1601-01-01 or earlier, but on
+    /// targets whose clock starts at 1970-01-01 it is the Unix epoch itself,
+    /// which is reported.
+    #[must_use]
+    pub fn last_v7_timestamp() -> Option<SystemTime> {
+        let last_ts = STATE.lock().last_ts();
+
+        (last_ts >= UNIX_EPOCH).then_some(last_ts)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+    use std::time::SystemTime;
+
+    use crate::UUID;
+
+    #[test]
+    fn reports_at_least_the_clock_reading_before_the_call() {
+        let before = SystemTime::now();
+
+        UUID::gen_v7().expect("generation must succeed");
+
+        let last = UUID::last_v7_timestamp().expect("a v7 timestamp was issued");
+
+        assert!(last >= before, "{last:?} must not precede {before:?}");
+    }
+
+    #[test]
+    fn matches_the_encoded_millisecond() {
+        let uuid = UUID::gen_v7().expect("generation must succeed");
+        let encoded = uuid.get_timestamp().expect("v7 UUIDs carry a timestamp");
+
+        // Concurrent tests may issue further ticks, which only move the
+        // reported timestamp forward.
+        let last = UUID::last_v7_timestamp().expect("a v7 timestamp was issued");
+
+        assert!(last >= encoded, "{last:?} must not precede {encoded:?}");
+    }
+}
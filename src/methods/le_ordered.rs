@@ -0,0 +1,104 @@
+//! Little-endian storage ordering for UUID.
+
+use core::cmp::Ordering;
+
+use crate::UUID;
+
+/// A UUID that sorts by its bytes read as a little-endian `u128`.
+///
+/// Stores that persist a UUID as a little-endian 128-bit integer, and then
+/// sort by that integer, order values by byte 15 first and byte 0 last:
+/// the reverse of the big-endian order [`UUID`]'s own `Ord` uses. Sorting a
+/// collection of `LeOrdered` mirrors such a store's order in memory.
+/// Equality and hashing are those of the wrapped UUID.
+///
+/// ```
+/// use ps_uuid::{LeOrdered, UUID};
+///
+/// let low: UUID = "ff000000-0000-0000-0000-000000000000".parse().unwrap();
+/// let high: UUID = "00000000-0000-0000-0000-000000000001".parse().unwrap();
+///
+/// assert!(low > high);
+/// assert!(LeOrdered(low) < LeOrdered(high));
+/// ```
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct LeOrdered(pub UUID);
+
+impl LeOrdered {
+    /// Returns the wrapped UUID's bytes read as a little-endian `u128`, the
+    /// key this wrapper sorts by.
+    #[must_use]
+    pub const fn key(&self) -> u128 {
+        u128::from_le_bytes(*self.0.as_bytes())
+    }
+}
+
+impl Ord for LeOrdered {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key().cmp(&other.key())
+    }
+}
+
+impl PartialOrd for LeOrdered {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl From<UUID> for LeOrdered {
+    #[inline]
+    fn from(uuid: UUID) -> Self {
+        Self(uuid)
+    }
+}
+
+impl From<LeOrdered> for UUID {
+    #[inline]
+    fn from(ordered: LeOrdered) -> Self {
+        ordered.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+    use super::LeOrdered;
+    use crate::UUID;
+
+    #[test]
+    fn orders_oppose_when_first_and_last_bytes_disagree() {
+        let a: UUID = "01000000-0000-0000-0000-000000000002"
+            .parse()
+            .expect("valid UUID");
+        let b: UUID = "02000000-0000-0000-0000-000000000001"
+            .parse()
+            .expect("valid UUID");
+
+        assert!(a < b);
+        assert!(LeOrdered(a) > LeOrdered(b));
+
+        let mut sorted = vec![LeOrdered(a), LeOrdered(b)];
+
+        sorted.sort();
+
+        assert_eq!(sorted, [LeOrdered(b), LeOrdered(a)]);
+    }
+
+    #[test]
+    fn key_is_the_little_endian_integer() {
+        let uuid = UUID::gen_v4();
+        let mut reversed = *uuid.as_bytes();
+
+        reversed.reverse();
+
+        assert_eq!(LeOrdered(uuid).key(), u128::from_be_bytes(reversed));
+    }
+
+    #[test]
+    fn equality_follows_the_wrapped_uuid() {
+        let uuid = UUID::gen_v4();
+
+        assert_eq!(LeOrdered(uuid), LeOrdered::from(uuid));
+        assert_eq!(UUID::from(LeOrdered(uuid)), uuid);
+    }
+}
@@ -0,0 +1,87 @@
+use crate::UUID;
+
+impl UUID {
+    /// Returns a key under which time-based UUIDs describing the same event
+    /// compare equal regardless of their version.
+    ///
+    /// For versions 1 and 6 the key is the version-6 layout (see
+    /// [`UUID::to_v6`]) with the version nibble and variant bits cleared: the
+    /// 60-bit timestamp, the 14-bit clock sequence and the node. A version-1
+    /// UUID and its version-6 conversion therefore share a key. Keys of
+    /// time-based UUIDs also sort by timestamp.
+    ///
+    /// Every other UUID, including version 2, whose timestamp is truncated,
+    /// is returned as its bytes unchanged.
+    #[must_use]
+    pub const fn logical_key(&self) -> [u8; 16] {
+        let Some(v6) = self.to_v6() else {
+            return self.bytes;
+        };
+
+        let mut key = v6.bytes;
+
+        key[6] &= 0x0F;
+        key[8] &= 0x3F;
+
+        key
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use crate::UUID;
+
+    #[test]
+    fn v1_and_its_v6_conversion_share_a_key() {
+        let v1 = UUID::gen_v1().expect("generation must succeed");
+        let v6 = v1.to_v6().expect("v1 converts");
+
+        assert_ne!(v1, v6);
+        assert_eq!(v1.logical_key(), v6.logical_key());
+    }
+
+    #[test]
+    fn version_and_variant_bits_are_zeroed() {
+        let key = UUID::NS_DNS.logical_key();
+
+        assert_eq!(
+            key,
+            [
+                0x1D, 0x19, 0xDA, 0xD6, 0xBA, 0x7B, 0x08, 0x10, 0x00, 0xB4, 0x00, 0xC0, 0x4F, 0xD4,
+                0x30, 0xC8
+            ]
+        );
+    }
+
+    #[test]
+    fn distinct_events_have_distinct_keys() {
+        let a = UUID::gen_v1().expect("generation must succeed");
+        let b = UUID::gen_v6().expect("generation must succeed");
+
+        assert_ne!(a.logical_key(), b.logical_key());
+    }
+
+    #[test]
+    fn keys_sort_by_timestamp() {
+        let node = [0xAA; 6];
+        let earlier = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        let later = earlier + Duration::from_micros(1);
+
+        // The higher clock sequence must not outrank the later timestamp.
+        let a = UUID::new_v1(earlier, 0x3FFF, node).expect("valid v1 inputs");
+        let b = UUID::new_v6(later, 0, node).expect("valid v6 inputs");
+
+        assert!(a.logical_key() < b.logical_key());
+    }
+
+    #[test]
+    fn other_uuids_are_their_own_key() {
+        let v4 = UUID::gen_v4();
+
+        assert_eq!(v4.logical_key(), *v4.as_bytes());
+        assert_eq!(UUID::max().logical_key(), [0xFF; 16]);
+    }
+}
@@ -1,11 +1,29 @@
 mod as_bytes;
 mod as_mut_bytes;
+mod birthday_collision_count;
+mod builder;
+mod byte;
+mod canonicalize;
+mod checked_add;
+mod checked_sub;
+mod const_eq;
+mod crc32;
+mod ct_is_nil;
+mod dcom_fields;
+mod debug_report;
+mod derive;
 mod duration_to_ticks;
+mod edit;
+mod entropy_bits;
+mod eq_str;
+mod expect_version;
+mod fill_v4;
 mod fmt_braced;
 mod fmt_hyphenated;
 mod fmt_simple;
 mod fmt_urn;
 mod from_bytes;
+mod from_fields_le;
 mod from_parts_dcom;
 mod from_parts_ncs;
 mod from_parts_v1;
@@ -16,20 +34,43 @@ mod from_parts_v5;
 mod from_parts_v6;
 mod from_parts_v7;
 mod from_parts_v8;
+mod from_short;
+mod from_simple;
 mod from_u128;
+mod from_u32_words;
+mod from_u64_pair;
 mod gen_dcom;
+mod gen_dcom_global;
 mod gen_ncs;
 mod gen_v1;
 mod gen_v2;
 mod gen_v4;
+mod gen_v4_from;
+mod gen_v4_into;
+mod gen_v4_with_prefix;
 mod gen_v6;
+mod gen_v6_retry;
 mod gen_v7;
+mod gen_v7_at_millis;
+mod gen_v7_in_range;
+mod gen_v7_mode;
+mod gen_v7_monotonic;
 mod get_clock_seq;
+mod get_ncs_address;
 mod get_node_id;
 mod get_timestamp;
 mod get_variant;
 mod get_version;
+mod group_by_version;
+mod hamming_distance;
+mod init_node_from_env;
+mod is_strictly_after;
+mod last_v7_timestamp;
+mod le_ordered;
+mod logical_key;
 mod max;
+mod ncs_address;
+mod ncs_fields;
 mod new_dcom;
 mod new_ncs;
 mod new_v1;
@@ -37,25 +78,72 @@ mod new_v2;
 mod new_v3;
 mod new_v4;
 mod new_v5;
+mod new_v5_str;
 mod new_v6;
 mod new_v7;
+mod new_v7_checked;
 mod new_v8;
+mod new_v8_time;
+mod nibble;
 mod nil;
+mod node_is_hardware;
+mod parse_braced_guid;
+mod parse_list;
+mod parse_with_annotation;
+mod posix_uuid;
 mod predicates;
+mod reset_global_state;
 mod set_variant;
 mod set_version;
+mod sort_by_time;
+mod split_ticks_v1;
+mod split_ticks_v6;
+mod sql_server_ordered;
 mod system_time_to_ticks;
+mod time_bucket;
+mod timestamp_is_plausible;
+mod timestamp_resolution;
+mod timestamp_span;
+mod to_grouped_string;
+mod to_log_key;
+mod to_short;
+mod to_simple_ascii;
+mod to_string_uppercase;
 mod to_u128;
+mod to_u32_words;
+mod to_u64_pair;
+mod to_v6;
+mod try_from_parts_v1;
+mod try_version;
+mod v3_hasher;
+mod v5_hasher;
+mod v7_counter;
+mod v7_now;
+mod v7_unix_millis;
+mod verify_v3;
+mod verify_v5;
+mod version_name;
+mod version_or;
+mod windows_guid;
+mod with_node_id;
+mod with_timestamp;
 mod with_variant;
 mod with_version;
 
 use std::time::Duration;
 
+pub use builder::Builder;
 pub use fmt_braced::Braced;
 pub use fmt_hyphenated::Hyphenated;
 pub use fmt_simple::Simple;
 pub use fmt_urn::Urn;
+pub use gen_v7_mode::V7Mode;
+pub use le_ordered::LeOrdered;
+pub use ncs_address::NcsAddress;
 pub use new_ncs::NcsUuidError;
+pub use posix_uuid::PosixUuid;
+pub use sql_server_ordered::SqlServerOrdered;
+pub use windows_guid::WindowsGuid;
 
 /// The number of 100-nanosecond intervals between the `FILETIME` epoch
 /// (1601-01-01T00:00:00Z) and the Unix epoch (1970-01-01T00:00:00Z).
@@ -0,0 +1,89 @@
+//! The 7-byte host address of an NCS UUID.
+
+use core::ops::Deref;
+
+/// The 7-byte host address carried in bytes 9‥=15 of an NCS variant UUID.
+///
+/// [`NodeId`](crate::NodeId) is 6 bytes, one short of the NCS node field,
+/// so this type gives the address its own name. [`UUID::new_ncs`] and
+/// [`UUID::gen_ncs`] accept anything convertible into it, including
+/// `[u8; 7]` and `&[u8; 7]`.
+///
+/// ```
+/// use std::time::{Duration, UNIX_EPOCH};
+///
+/// use ps_uuid::{NcsAddress, UUID};
+///
+/// let address = NcsAddress([0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07]);
+/// let time = UNIX_EPOCH + Duration::from_secs(315_532_800 + 3600);
+/// let uuid = UUID::new_ncs(time, 2, address).unwrap();
+///
+/// assert_eq!(uuid.get_ncs_address(), Some(address.0));
+/// ```
+///
+/// [`UUID::new_ncs`]: crate::UUID::new_ncs
+/// [`UUID::gen_ncs`]: crate::UUID::gen_ncs
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct NcsAddress(pub [u8; 7]);
+
+impl Deref for NcsAddress {
+    type Target = [u8; 7];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl From<[u8; 7]> for NcsAddress {
+    #[inline]
+    fn from(bytes: [u8; 7]) -> Self {
+        Self(bytes)
+    }
+}
+
+impl From<&[u8; 7]> for NcsAddress {
+    #[inline]
+    fn from(bytes: &[u8; 7]) -> Self {
+        Self(*bytes)
+    }
+}
+
+impl From<NcsAddress> for [u8; 7] {
+    #[inline]
+    fn from(address: NcsAddress) -> Self {
+        address.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use super::NcsAddress;
+    use crate::UUID;
+
+    const BYTES: [u8; 7] = [0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0xFF];
+
+    #[test]
+    fn conversions_preserve_the_bytes() {
+        assert_eq!(NcsAddress::from(BYTES), NcsAddress(BYTES));
+        assert_eq!(NcsAddress::from(&BYTES), NcsAddress(BYTES));
+        assert_eq!(<[u8; 7]>::from(NcsAddress(BYTES)), BYTES);
+        assert_eq!(*NcsAddress(BYTES), BYTES);
+    }
+
+    #[test]
+    fn every_spelling_builds_the_same_uuid() {
+        let time = UNIX_EPOCH + Duration::from_secs(315_532_800 + 3600);
+
+        let typed = UUID::new_ncs(time, 2, NcsAddress(BYTES)).expect("valid NCS inputs");
+        let owned = UUID::new_ncs(time, 2, BYTES).expect("valid NCS inputs");
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        let borrowed = UUID::new_ncs(time, 2, &BYTES).expect("valid NCS inputs");
+
+        assert_eq!(typed, owned);
+        assert_eq!(typed, borrowed);
+        assert_eq!(typed.get_ncs_address(), Some(BYTES));
+    }
+}
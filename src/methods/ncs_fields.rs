@@ -0,0 +1,77 @@
+use crate::{Variant, UUID};
+
+impl UUID {
+    /// Decodes the fields of an NCS variant UUID, the inverse of
+    /// [`UUID::from_parts_ncs`] and [`UUID::new_ncs`].
+    ///
+    /// Returns `(timestamp, address_family, address)`, where `timestamp` is
+    /// the 48-bit big-endian count of 4-microsecond units since
+    /// 1980-01-01 00:00 UTC. The variant occupies the top bit of the address
+    /// family byte, so only its low 7 bits are returned; the two reserved
+    /// bytes are not.
+    ///
+    /// Returns `None` if the UUID is not of the [`Variant::NCS`] variant.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ps_uuid::UUID;
+    ///
+    /// let ticks: u64 = 0x1234_5678_9ABC;
+    /// let address = [1, 2, 3, 4, 5, 6, 7];
+    /// let timestamp: [u8; 6] = ticks.to_be_bytes()[2..].try_into().unwrap();
+    ///
+    /// let uuid = UUID::from_parts_ncs(&timestamp, 13, &address);
+    ///
+    /// assert_eq!(uuid.ncs_fields(), Some((ticks, 13, address)));
+    /// ```
+    #[must_use]
+    pub const fn ncs_fields(&self) -> Option<(u64, u8, [u8; 7])> {
+        if !matches!(self.get_variant(), Variant::NCS) {
+            return None;
+        }
+
+        let [t0, t1, t2, t3, t4, t5, _, _, family, a0, a1, a2, a3, a4, a5, a6] = self.bytes;
+
+        Some((
+            u64::from_be_bytes([0, 0, t0, t1, t2, t3, t4, t5]),
+            family & 0x7F,
+            [a0, a1, a2, a3, a4, a5, a6],
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use crate::UUID;
+
+    const ADDRESS: [u8; 7] = [0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07];
+
+    #[test]
+    fn roundtrips_from_parts_ncs() {
+        for (ticks, family) in [(0, 0), (0x1234_5678_9ABC, 2), ((1 << 48) - 1, 13)] {
+            let timestamp: [u8; 6] = u64::to_be_bytes(ticks)[2..].try_into().expect("six bytes");
+            let uuid = UUID::from_parts_ncs(&timestamp, family, &ADDRESS);
+
+            assert_eq!(uuid.ncs_fields(), Some((ticks, family, ADDRESS)));
+        }
+    }
+
+    #[test]
+    fn decodes_new_ncs() {
+        // One hour after the NCS epoch: 3 600 s / 4 µs = 900 000 000 ticks.
+        let time = UNIX_EPOCH + Duration::from_secs(315_532_800 + 3600);
+        let uuid = UUID::new_ncs(time, 2, ADDRESS).expect("valid NCS inputs");
+
+        assert_eq!(uuid.ncs_fields(), Some((900_000_000, 2, ADDRESS)));
+    }
+
+    #[test]
+    fn other_variants_yield_none() {
+        assert_eq!(UUID::gen_v4().ncs_fields(), None);
+        assert_eq!(UUID::max().ncs_fields(), None);
+    }
+}
@@ -1,6 +1,6 @@
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
-use crate::UUID;
+use crate::{NcsAddress, UUID};
 
 const NCS_EPOCH: Duration = Duration::from_secs(315_532_800); // January 1, 1980 (Unix epoch + 10 years)
 const MAX_TIMESTAMP: u64 = (1u64 << 48) - 1; // Max value for 48-bit timestamp
@@ -16,8 +16,10 @@ pub enum NcsUuidError {
     /// The timestamp precedes the NCS epoch of 1980-01-01.
     #[error("Timestamp is before 1980-01-01")]
     TimestampBeforeEpoch,
-    /// The timestamp exceeds the representable range.
-    #[error("Timestamp is after 2015-09-05T05:58:26.842Z")]
+    /// The timestamp exceeds the representable range: the 48-bit count of
+    /// 4-microsecond units since 1980-01-01 ends at the tick starting
+    /// 2015-09-05T05:58:26.842620Z.
+    #[error("Timestamp is at or after 2015-09-05T05:58:26.842624Z, beyond the 48-bit NCS range")]
     TimestampOverflow,
 }
 
@@ -27,7 +29,8 @@ impl UUID {
     /// # Parameters
     /// - `timestamp`: System time for UUID generation.
     /// - `address_family`: Network address family (0–13, per NCS specification).
-    /// - `address`: 7-byte node ID (e.g., extended MAC address or unique host ID).
+    /// - `address`: 7-byte node ID (e.g., extended MAC address or unique host ID),
+    ///   as an [`NcsAddress`] or anything convertible into one, such as `&[u8; 7]`.
     ///
     /// # NCS UUID Structure
     /// - Timestamp (48 bits): 4-microsecond units since 1980-01-01 00:00 UTC.
@@ -42,7 +45,7 @@ impl UUID {
     /// # Errors
     /// - `AddressFamilyOutOfRange` is returned if `address_family` doesn't satisfy `0..=13`
     /// - `TimestampBeforeEpoch` is returned if `timestamp` is before `1980-01-01`
-    /// - `TimestampOverflow` is returned if `timestamp` is after `2015-09-05T05:58:26.842Z`
+    /// - `TimestampOverflow` is returned if `timestamp` is at or after `2015-09-05T05:58:26.842624Z`
     ///
     /// # Example
     /// ```rust
@@ -53,7 +56,7 @@ impl UUID {
     pub fn new_ncs(
         timestamp: SystemTime,
         address_family: u8,
-        address: &[u8; 7],
+        address: impl Into<NcsAddress>,
     ) -> Result<Self, NcsUuidError> {
         // Validate address family (0–13 for NCS compatibility)
         if address_family > 13 {
@@ -81,7 +84,7 @@ impl UUID {
         bytes[8] = address_family;
 
         // Set node ID (bytes 9–15)
-        bytes[9..16].copy_from_slice(address);
+        bytes[9..16].copy_from_slice(&*address.into());
 
         // Set NCS variant (0xxx₂ in most significant bits of byte 8)
         bytes[8] &= !NCS_VARIANT_MASK;
@@ -98,7 +101,7 @@ mod tests {
     fn test_valid_ncs_uuid() -> Result<(), NcsUuidError> {
         let time = UNIX_EPOCH + NCS_EPOCH + Duration::from_secs(3600); // 1 hour after epoch
         let address = [0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07];
-        let uuid = UUID::new_ncs(time, 2, &address)?;
+        let uuid = UUID::new_ncs(time, 2, address)?;
         let bytes = uuid.as_bytes();
         // Check timestamp (3600s = 900,000,000 4μs units)
         let expected_timestamp = (900_000_000u64).to_be_bytes();
@@ -116,7 +119,7 @@ mod tests {
     fn test_timestamp_before_epoch() {
         let time = UNIX_EPOCH; // Before 1980
         let address = [0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07];
-        let result = UUID::new_ncs(time, 2, &address);
+        let result = UUID::new_ncs(time, 2, address);
         assert!(matches!(result, Err(NcsUuidError::TimestampBeforeEpoch)));
     }
 
@@ -124,17 +127,72 @@ mod tests {
     fn test_invalid_address_family() {
         let time = UNIX_EPOCH + NCS_EPOCH + Duration::from_secs(3600);
         let address = [0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07];
-        let result = UUID::new_ncs(time, 14, &address);
+        let result = UUID::new_ncs(time, 14, address);
         assert!(matches!(result, Err(NcsUuidError::AddressFamilyOutOfRange)));
     }
 
     #[test]
     fn test_nil_uuid() -> Result<(), NcsUuidError> {
-        let ncs_nil = UUID::new_ncs(UNIX_EPOCH + NCS_EPOCH, 0, &[0, 0, 0, 0, 0, 0, 0])?;
+        let ncs_nil = UUID::new_ncs(UNIX_EPOCH + NCS_EPOCH, 0, [0, 0, 0, 0, 0, 0, 0])?;
         let nil = UUID::nil();
 
         assert_eq!(ncs_nil, nil, "UUIDs should be equal.");
 
         Ok(())
     }
+
+    /// The last 4 µs tick the 48-bit field holds starts at
+    /// 2015-09-05T05:58:26.842620Z.
+    const LAST_TICK: Duration = Duration::from_micros(MAX_TIMESTAMP * 4);
+
+    #[test]
+    fn test_timestamp_boundary() -> Result<(), NcsUuidError> {
+        let address = [0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07];
+        let last_tick = UNIX_EPOCH + NCS_EPOCH + LAST_TICK;
+
+        assert_eq!(
+            last_tick,
+            UNIX_EPOCH + Duration::new(1_441_432_706, 842_620_000),
+            "The last tick must start at 2015-09-05T05:58:26.842620Z."
+        );
+
+        // One tick below the maximum.
+        let below = UUID::new_ncs(last_tick - Duration::from_micros(4), 2, address)?;
+        assert_eq!(
+            &below.as_bytes()[0..6],
+            &(MAX_TIMESTAMP - 1).to_be_bytes()[2..8]
+        );
+
+        // The maximum itself, including its final nanosecond.
+        let at = UUID::new_ncs(last_tick + Duration::from_nanos(3_999), 2, address)?;
+        assert_eq!(&at.as_bytes()[0..6], &MAX_TIMESTAMP.to_be_bytes()[2..8]);
+
+        // One tick above the maximum.
+        let above = UUID::new_ncs(last_tick + Duration::from_micros(4), 2, address);
+        assert!(matches!(above, Err(NcsUuidError::TimestampOverflow)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_overflow_message_names_the_cutoff() {
+        assert_eq!(
+            NcsUuidError::TimestampOverflow.to_string(),
+            "Timestamp is at or after 2015-09-05T05:58:26.842624Z, beyond the 48-bit NCS range"
+        );
+    }
+
+    #[test]
+    fn test_address_family_boundary() -> Result<(), NcsUuidError> {
+        let time = UNIX_EPOCH + NCS_EPOCH + Duration::from_secs(3600);
+        let address = [0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07];
+
+        let uuid = UUID::new_ncs(time, 13, address)?;
+        assert_eq!(uuid.as_bytes()[8], 13);
+
+        let result = UUID::new_ncs(time, 14, address);
+        assert!(matches!(result, Err(NcsUuidError::AddressFamilyOutOfRange)));
+
+        Ok(())
+    }
 }
@@ -21,9 +21,7 @@ impl UUID {
         // ------------------------------------------------------------------
         // 2. Split the 60-bit timestamp into the three wire fields
         // ------------------------------------------------------------------
-        let time_low: u32 = (ticks & 0xFFFF_FFFF) as u32;
-        let time_mid: u16 = ((ticks >> 32) & 0xFFFF) as u16;
-        let time_hi: u16 = ((ticks >> 48) & 0x0FFF) as u16; // only 12 bits
+        let (time_low, time_mid, time_hi) = Self::split_ticks_v1(ticks);
 
         // ------------------------------------------------------------------
         // 3. Construct UUID
@@ -79,6 +77,19 @@ mod tests {
         assert_eq!(&bytes[10..16], &mac);
     }
 
+    #[test]
+    fn fixed_inputs_produce_deterministic_bytes() {
+        let t = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        let mac = [0xAA, 0xBB, 0xCC, 0xDD, 0xEE, 0xFF];
+
+        let first = UUID::new_v1(t, 0x2A3B, mac).expect("new_v1 should succeed");
+        let second = UUID::new_v1(t, 0x2A3B, mac).expect("new_v1 should succeed");
+
+        assert_eq!(first, second);
+        assert_eq!(first.to_string(), "04afc000-833b-11ee-aa3b-aabbccddeeff");
+        assert_eq!(first, manual(t, mac));
+    }
+
     // The test instant precedes 1601-01-01, which only platforms with a
     // signed clock representation can express.
     #[cfg(unix)]
@@ -151,4 +162,31 @@ mod tests {
             "wrong error variant: got {err:?}"
         );
     }
+
+    #[test]
+    fn last_tick_is_accepted_and_the_next_is_rejected() {
+        // 2^60 - 1 ticks after 1582-10-15 falls on 5236-03-31.
+        const MAX_TICKS: u64 = 0x0FFF_FFFF_FFFF_FFFF;
+        let since_unix = (Duration::from_nanos(MAX_TICKS) * 100)
+            .checked_sub(GREGORIAN_OFFSET)
+            .expect("the last tick follows the Unix epoch");
+        let last = UNIX_EPOCH + since_unix;
+
+        assert_eq!(UUID::system_time_to_ticks(last), Ok(MAX_TICKS));
+
+        for time in [last, last + Duration::from_nanos(99)] {
+            let uuid = UUID::new_v1(time, 0, [0; 6]).expect("the last tick must be accepted");
+
+            assert!(
+                uuid.to_string().starts_with("ffffffff-ffff-1fff-"),
+                "{uuid}"
+            );
+            assert_eq!(uuid.get_timestamp(), Some(last));
+        }
+
+        assert_eq!(
+            UUID::new_v1(last + Duration::from_nanos(100), 0, [0; 6]),
+            Err(UuidConstructionError::TimestampOverflow)
+        );
+    }
 }
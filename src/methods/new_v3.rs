@@ -1,4 +1,4 @@
-use crate::{Md5, UUID};
+use crate::UUID;
 
 impl UUID {
     /// Builds an RFC-4122 Version-3 UUID from `namespace || name`.
@@ -12,9 +12,8 @@ impl UUID {
     where
         N: AsRef<[u8]>,
     {
-        let mut hasher = Md5::new();
+        let mut hasher = Self::v3_hasher(namespace);
 
-        hasher.update(namespace.as_bytes());
         hasher.update(name.as_ref());
 
         let digest = hasher.finalize();
@@ -28,9 +27,7 @@ impl UUID {
 // ────────────────────────────────────────────────────────────────────────────
 #[cfg(test)]
 mod tests {
-    use crate::UUID;
-
-    use super::*;
+    use crate::{Md5, UUID};
 
     // Helper: two MSBs must be `10`
     const fn is_rfc4122_variant(b: u8) -> bool {
@@ -1,4 +1,4 @@
-use crate::{Sha1, UUID};
+use crate::UUID;
 
 impl UUID {
     /// Builds an RFC-4122 Version-5 UUID from `namespace || name`.
@@ -11,9 +11,8 @@ impl UUID {
     where
         N: AsRef<[u8]>,
     {
-        let mut hasher = Sha1::new();
+        let mut hasher = Self::v5_hasher(namespace);
 
-        hasher.update(namespace.as_bytes());
         hasher.update(name.as_ref());
 
         let digest = hasher.finalize();
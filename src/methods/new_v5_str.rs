@@ -0,0 +1,55 @@
+use crate::UUID;
+
+impl UUID {
+    /// Builds an RFC-4122 Version-5 UUID from `namespace` and a string name.
+    ///
+    /// The name is hashed as its UTF-8 bytes, exactly as [`UUID::new_v5`]
+    /// would hash `name.as_bytes()`. This matches Python's `uuid.uuid5` and
+    /// most other implementations, which also encode string names as UTF-8.
+    /// Names that another system hashes in a different encoding (for example
+    /// UTF-16 or Latin-1) must be encoded by the caller and passed to
+    /// [`UUID::new_v5`] instead.
+    #[must_use]
+    pub fn new_v5_str(namespace: &Self, name: &str) -> Self {
+        Self::new_v5(namespace, name.as_bytes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::UUID;
+
+    #[test]
+    fn matches_new_v5_over_utf8_bytes() {
+        let name = "žluťoučký kůň";
+
+        assert_eq!(
+            UUID::new_v5_str(&UUID::NS_DNS, name),
+            UUID::new_v5(&UUID::NS_DNS, name.as_bytes())
+        );
+    }
+
+    #[test]
+    fn matches_python_uuid5_for_unicode_names() {
+        // uuid.uuid5(uuid.NAMESPACE_DNS, "münchen.example")
+        assert_eq!(
+            UUID::new_v5_str(&UUID::NS_DNS, "münchen.example").to_string(),
+            "b0a686dd-7bbb-5935-9663-c50a1bc538c3"
+        );
+
+        // uuid.uuid5(uuid.NAMESPACE_URL, "https://例え.jp/")
+        assert_eq!(
+            UUID::new_v5_str(&UUID::NS_URL, "https://例え.jp/").to_string(),
+            "eccf5197-3987-5080-845b-82d9b4c8af77"
+        );
+    }
+
+    #[test]
+    fn matches_python_uuid5_for_ascii_names() {
+        // uuid.uuid5(uuid.NAMESPACE_DNS, "python.org")
+        assert_eq!(
+            UUID::new_v5_str(&UUID::NS_DNS, "python.org").to_string(),
+            "886313e1-3b8a-5372-9b90-0c9aee199e5d"
+        );
+    }
+}
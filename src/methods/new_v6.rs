@@ -5,6 +5,10 @@ impl UUID {
     /// Create an RFC 4122 **version-6** (time-ordered) UUID from a
     /// `SystemTime`, a 14-bit clock sequence and a 48-bit node identifier.
     ///
+    /// Only the low 14 bits of `clock_seq` are encoded: its upper two bits
+    /// share a byte with the variant and are overwritten by it, so values
+    /// that differ only above `0x3FFF` produce the same UUID.
+    ///
     /// # Errors
     /// - `TimestampBeforeEpoch` if `time` predates 1582-10-15.
     /// - `TimestampOverflow`    if the 60-bit tick counter would overflow.
@@ -17,9 +21,7 @@ impl UUID {
         let ticks = Self::system_time_to_ticks(time)?;
 
         // 2. Split the 60-bit timestamp into (high, mid, low) pieces
-        let time_high: u32 = ((ticks >> 28) & 0xFFFF_FFFF) as u32; // most-significant 32
-        let time_mid: u16 = ((ticks >> 12) & 0xFFFF) as u16; // next 16
-        let time_low: u16 = (ticks & 0x0FFF) as u16; // least-significant 12
+        let (time_high, time_mid, time_low) = Self::split_ticks_v6(ticks);
 
         // 3. Assemble the UUID
         Ok(Self::from_parts_v6(
@@ -124,4 +126,42 @@ mod tests {
             .expect_err("new_v6 should reject timestamps after 5236-03-31");
         assert_eq!(err, UuidConstructionError::TimestampOverflow);
     }
+
+    #[test]
+    fn clock_seq_is_masked_to_14_bits() {
+        let t = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        let node = [1, 2, 3, 4, 5, 6];
+        let build = |clock_seq| UUID::new_v6(t, clock_seq, node).expect("new_v6 should succeed");
+
+        assert_eq!(build(0x4000), build(0x0000));
+        assert_eq!(build(0xFFFF), build(0x3FFF));
+        assert_eq!(build(0xFFFF).get_clock_seq(), Some(0x3FFF));
+    }
+
+    #[test]
+    fn last_tick_is_accepted_and_the_next_is_rejected() {
+        // 2^60 - 1 ticks after 1582-10-15 falls on 5236-03-31.
+        const MAX_TICKS: u64 = 0x0FFF_FFFF_FFFF_FFFF;
+        let since_unix = (Duration::from_nanos(MAX_TICKS) * 100)
+            .checked_sub(GREGORIAN_OFFSET)
+            .expect("the last tick follows the Unix epoch");
+        let last = UNIX_EPOCH + since_unix;
+
+        assert_eq!(UUID::system_time_to_ticks(last), Ok(MAX_TICKS));
+
+        for time in [last, last + Duration::from_nanos(99)] {
+            let uuid = UUID::new_v6(time, 0, [0; 6]).expect("the last tick must be accepted");
+
+            assert!(
+                uuid.to_string().starts_with("ffffffff-ffff-6fff-"),
+                "{uuid}"
+            );
+            assert_eq!(uuid.get_timestamp(), Some(last));
+        }
+
+        assert_eq!(
+            UUID::new_v6(last + Duration::from_nanos(100), 0, [0; 6]),
+            Err(UuidConstructionError::TimestampOverflow)
+        );
+    }
 }
@@ -22,7 +22,10 @@ impl UUID {
     ///
     /// The function never fails; any excess upper bits in the timestamp are
     /// truncated, and the *version* \(0b0111\) and *variant* \(0b10xxxxxx\)
-    /// fields are fixed automatically.
+    /// fields are fixed automatically. Truncation wraps a timestamp at or
+    /// beyond 2⁴⁸ ms (≈ 10889-08-02) back towards 1970; use
+    /// [`UUID::new_v7_checked`] to get a `TimestampOverflow` error instead,
+    /// which is what [`UUID::gen_v7`] returns for such a clock reading.
     #[must_use]
     pub fn new_v7(timestamp: Duration, random_bytes: [u8; 8]) -> Self {
         let mut uuid = Self::nil();
@@ -115,6 +118,30 @@ mod tests {
         assert_eq!(bottom.as_bytes()[7], 0x00);
     }
 
+    #[test]
+    fn sub_millisecond_sweep_keeps_version_and_orders_rand_a() {
+        let base = Duration::from_millis(0x0123_4567_89AB);
+
+        let mut previous = 0u16;
+
+        for nanos in (0..1_000_000u32).step_by(97) {
+            let uuid = UUID::new_v7(base + Duration::from_nanos(nanos.into()), [0xFF; 8]);
+            let b = uuid.as_bytes();
+            let rand_a = u16::from_be_bytes([b[6], b[7]]) & 0x0FFF;
+
+            assert_eq!(version(b), 0b0111, "version nibble corrupted at {nanos} ns");
+            assert_eq!(
+                u64::from(rand_a),
+                u64::from(nanos) * 4096 / 1_000_000,
+                "rand_a is not the scaled fraction at {nanos} ns"
+            );
+            assert!(rand_a >= previous, "rand_a decreased at {nanos} ns");
+            assert_eq!(&b[0..6], &[0x01, 0x23, 0x45, 0x67, 0x89, 0xAB]);
+
+            previous = rand_a;
+        }
+    }
+
     #[test]
     fn a_256_ns_step_always_advances_the_encoding() {
         // The generator's self-advance is 256 ns; each step must strictly
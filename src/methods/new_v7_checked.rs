@@ -0,0 +1,61 @@
+use std::time::Duration;
+
+use crate::{UuidConstructionError, UUID};
+
+/// The first millisecond count the 48-bit `unix_ts_ms` field cannot hold.
+const MAX_MILLIS: u128 = 1 << 48;
+
+impl UUID {
+    /// Build a **Version 7** UUID exactly like [`UUID::new_v7`], but reject
+    /// a timestamp whose millisecond count does not fit the 48-bit field
+    /// instead of truncating it.
+    ///
+    /// [`UUID::gen_v7`] and [`Builder`](crate::Builder) apply the same
+    /// check, so this is the constructor to use when a caller-supplied time
+    /// must either round-trip through [`UUID::get_timestamp`] or fail.
+    ///
+    /// # Errors
+    /// - `TimestampOverflow` is returned if `timestamp` reaches 2⁴⁸ ms
+    ///   (≈ 10889-08-02).
+    pub fn new_v7_checked(
+        timestamp: Duration,
+        random_bytes: [u8; 8],
+    ) -> Result<Self, UuidConstructionError> {
+        if timestamp.as_millis() >= MAX_MILLIS {
+            return Err(UuidConstructionError::TimestampOverflow);
+        }
+
+        Ok(Self::new_v7(timestamp, random_bytes))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::{UuidConstructionError, UUID};
+
+    #[test]
+    fn in_range_matches_new_v7() {
+        let timestamp = Duration::from_millis((1 << 48) - 1) + Duration::from_nanos(999_999);
+
+        assert_eq!(
+            UUID::new_v7_checked(timestamp, [0xA5; 8]),
+            Ok(UUID::new_v7(timestamp, [0xA5; 8]))
+        );
+    }
+
+    #[test]
+    fn overflow_errors_where_new_v7_truncates() {
+        let timestamp = Duration::from_millis(1 << 48);
+
+        assert_eq!(
+            UUID::new_v7_checked(timestamp, [0; 8]),
+            Err(UuidConstructionError::TimestampOverflow)
+        );
+        assert_eq!(
+            UUID::new_v7(timestamp, [0; 8]),
+            UUID::new_v7(Duration::ZERO, [0; 8])
+        );
+    }
+}
@@ -0,0 +1,93 @@
+use crate::UUID;
+
+impl UUID {
+    /// Build a **Version 8** UUID that sorts by time like version 7, with an
+    /// application-defined payload.
+    ///
+    /// Layout (big-endian):
+    ///
+    /// ```text
+    ///   0‥47   unix_ts_ms   48-bit Unix-epoch time in milliseconds
+    ///   48‥51  version      0b1000
+    ///   52‥63  custom[0‥2]  low nibble of custom[0], then custom[1]
+    ///   64‥65  variant      0b10
+    ///   66‥127 custom[2‥10] custom[2] without its top two bits, then the rest
+    /// ```
+    ///
+    /// Only the least-significant 48 bits of `unix_ms` are used. `custom`
+    /// fills bytes 6‥16, except for the high nibble of `custom[0]` and the
+    /// top two bits of `custom[2]`, which the version and variant overwrite,
+    /// leaving 74 payload bits.
+    ///
+    /// RFC 9562 leaves the meaning of version-8 fields to the application,
+    /// so other implementations will not recognize the timestamp, and
+    /// [`UUID::get_timestamp`] does not decode it.
+    ///
+    /// # Example
+    /// ```
+    /// use ps_uuid::UUID;
+    ///
+    /// let uuid = UUID::new_v8_time(1_700_000_000_000, [0xAB; 10]);
+    ///
+    /// assert_eq!(uuid.get_version(), Some(8));
+    /// assert_eq!(uuid.to_string(), "018bcfe5-6800-8bab-abab-abababababab");
+    /// ```
+    #[must_use]
+    pub const fn new_v8_time(unix_ms: u64, custom: [u8; 10]) -> Self {
+        let [_, _, t0, t1, t2, t3, t4, t5] = unix_ms.to_be_bytes();
+        let [c0, c1, c2, c3, c4, c5, c6, c7, c8, c9] = custom;
+
+        Self::from_bytes([
+            t0, t1, t2, t3, t4, t5, c0, c1, c2, c3, c4, c5, c6, c7, c8, c9,
+        ])
+        .with_version(8)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Variant, UUID};
+
+    fn millis(uuid: &UUID) -> u64 {
+        let b = uuid.as_bytes();
+
+        u64::from_be_bytes([0, 0, b[0], b[1], b[2], b[3], b[4], b[5]])
+    }
+
+    #[test]
+    fn timestamp_occupies_the_first_six_bytes() {
+        let uuid = UUID::new_v8_time(1_700_000_000_123, [0; 10]);
+
+        assert_eq!(millis(&uuid), 1_700_000_000_123);
+        assert_eq!(uuid.get_version(), Some(8));
+        assert_eq!(uuid.get_variant(), Variant::OSF);
+    }
+
+    #[test]
+    fn custom_bits_survive_outside_version_and_variant() {
+        let custom = [0xFF; 10];
+        let uuid = UUID::new_v8_time(0, custom);
+
+        assert_eq!(uuid, UUID::from_u128(u128::MAX >> 48).with_version(8));
+        assert_eq!(
+            uuid.as_bytes()[6..],
+            [0x8F, 0xFF, 0xBF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF]
+        );
+    }
+
+    #[test]
+    fn only_48_bits_of_the_timestamp_are_used() {
+        assert_eq!(
+            UUID::new_v8_time(u64::MAX, [0; 10]),
+            UUID::new_v8_time(0xFFFF_FFFF_FFFF, [0; 10])
+        );
+    }
+
+    #[test]
+    fn sorts_by_timestamp_first() {
+        let earlier = UUID::new_v8_time(1_000, [0xFF; 10]);
+        let later = UUID::new_v8_time(1_001, [0; 10]);
+
+        assert!(earlier < later);
+    }
+}
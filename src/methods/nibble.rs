@@ -0,0 +1,66 @@
+use crate::UUID;
+
+impl UUID {
+    /// Returns the 4-bit group at position `i`, i.e. the value of the
+    /// `i`-th hex digit of the simple string form, counting from 0.
+    ///
+    /// The version of an OSF-variant UUID is `nibble(12)`, the first digit
+    /// of the third group in the hyphenated form.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i >= 32`; in a const context this is a compile-time error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ps_uuid::UUID;
+    ///
+    /// const VERSION: u8 = UUID::NS_DNS.nibble(12);
+    ///
+    /// assert_eq!(VERSION, 1);
+    /// assert_eq!(UUID::gen_v4().nibble(12), 4);
+    /// ```
+    #[must_use]
+    pub const fn nibble(&self, i: usize) -> u8 {
+        let byte = self.bytes[i / 2];
+
+        if i.is_multiple_of(2) {
+            byte >> 4
+        } else {
+            byte & 0x0F
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::UUID;
+
+    #[test]
+    fn nibble_12_is_the_version() {
+        for _ in 0..100 {
+            assert_eq!(UUID::gen_v4().nibble(12), 4);
+        }
+    }
+
+    #[test]
+    fn matches_the_simple_form() {
+        let uuid = UUID::gen_v4();
+        let simple = uuid.to_simple_ascii();
+
+        for (i, &digit) in simple.iter().enumerate() {
+            assert_eq!(
+                Some(u32::from(uuid.nibble(i))),
+                char::from(digit).to_digit(16),
+                "nibble {i}"
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn out_of_range_panics() {
+        let _ = UUID::nil().nibble(32);
+    }
+}
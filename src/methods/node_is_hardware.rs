@@ -0,0 +1,50 @@
+use crate::UUID;
+
+impl UUID {
+    /// Reports whether the node ID may be a hardware MAC address.
+    ///
+    /// Returns `Some(false)` if the multicast bit of the node ID is set,
+    /// which marks a random node ID (see [`NodeId::is_multicast`]), and
+    /// `Some(true)` otherwise, in which case the UUID may reveal the MAC
+    /// address of the machine that generated it. Returns `None` if the UUID
+    /// carries no node ID (see [`UUID::get_node_id`]).
+    ///
+    /// [`NodeId::is_multicast`]: crate::NodeId::is_multicast
+    #[must_use]
+    pub const fn node_is_hardware(&self) -> Option<bool> {
+        match self.get_node_id() {
+            Some(node_id) => Some(!node_id.is_multicast()),
+            None => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+    use std::time::SystemTime;
+
+    use crate::UUID;
+
+    #[test]
+    fn unicast_node_may_be_hardware() {
+        let uuid = UUID::new_v1(SystemTime::now(), 0, [0x00, 0x1B, 0x63, 0x84, 0x45, 0xE6])
+            .expect("new_v1 should succeed");
+
+        assert_eq!(uuid.node_is_hardware(), Some(true));
+    }
+
+    #[test]
+    fn multicast_node_is_random() {
+        let uuid = UUID::new_v1(SystemTime::now(), 0, [0x01, 0x1B, 0x63, 0x84, 0x45, 0xE6])
+            .expect("new_v1 should succeed");
+
+        assert_eq!(uuid.node_is_hardware(), Some(false));
+    }
+
+    #[test]
+    fn versions_without_node_yield_none() {
+        assert_eq!(UUID::gen_v4().node_is_hardware(), None);
+        assert_eq!(UUID::nil().node_is_hardware(), None);
+    }
+}
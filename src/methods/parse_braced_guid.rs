@@ -0,0 +1,129 @@
+use crate::{UuidParseError, UUID};
+
+impl UUID {
+    /// Parses a braced GUID string, reading its first three groups as the
+    /// little-endian `Data1`, `Data2` and `Data3` fields of a Microsoft
+    /// `GUID` and returning the UUID laid out in the GUID's in-memory
+    /// (mixed-endian) byte order.
+    ///
+    /// [`FromStr`](std::str::FromStr) also accepts `{…}`, but keeps the RFC
+    /// byte order: the bytes of the result appear in the same order as the
+    /// hex digits, which is what Windows registry dumps and most tools
+    /// expect. Use this function instead when the braced text was printed
+    /// from a `GUID` whose raw bytes are to be stored as-is, e.g. when
+    /// round-tripping through [`UUID::from_fields_le`].
+    ///
+    /// The braces are required; the content may use either the hyphenated
+    /// or the 32-digit form.
+    ///
+    /// # Errors
+    ///
+    /// - [`UuidParseError::InvalidBraces`] if `s` is not wrapped in `{}`.
+    /// - Any error [`FromStr`](std::str::FromStr) returns for the content.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ps_uuid::UUID;
+    ///
+    /// let s = "{00112233-4455-6677-8899-aabbccddeeff}";
+    ///
+    /// assert_eq!(
+    ///     UUID::parse_braced_guid(s).unwrap().to_string(),
+    ///     "33221100-5544-7766-8899-aabbccddeeff"
+    /// );
+    /// ```
+    pub fn parse_braced_guid(s: &str) -> Result<Self, UuidParseError> {
+        let inner = s
+            .strip_prefix('{')
+            .and_then(|s| s.strip_suffix('}'))
+            .ok_or(UuidParseError::InvalidBraces)?;
+
+        if inner.starts_with('{') || inner.ends_with('}') {
+            return Err(UuidParseError::InvalidBraces);
+        }
+
+        // Only the hyphenated and 32-digit forms may appear inside the braces.
+        if !matches!(inner.len(), 32 | 36) {
+            return Err(UuidParseError::InvalidLength);
+        }
+
+        let [d0, d1, d2, d3, d4, d5, d6, d7, rest @ ..] = *inner.parse::<Self>()?.as_bytes();
+
+        Ok(Self::from_fields_le(
+            u32::from_be_bytes([d0, d1, d2, d3]),
+            u16::from_be_bytes([d4, d5]),
+            u16::from_be_bytes([d6, d7]),
+            &rest,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+    use crate::{UuidParseError, UUID};
+
+    const BRACED: &str = "{6ba7b810-9dad-11d1-80b4-00c04fd430c8}";
+
+    #[test]
+    fn differs_from_rfc_order_braced_parsing() {
+        let rfc: UUID = BRACED.parse().expect("valid braced UUID");
+        let guid = UUID::parse_braced_guid(BRACED).expect("valid braced GUID");
+
+        assert_eq!(rfc, UUID::NS_DNS);
+        assert_ne!(guid, rfc);
+        assert_eq!(guid.to_string(), "10b8a76b-ad9d-d111-80b4-00c04fd430c8");
+    }
+
+    #[test]
+    fn matches_from_fields_le() {
+        let guid = UUID::parse_braced_guid(BRACED).expect("valid braced GUID");
+
+        assert_eq!(
+            guid,
+            UUID::from_fields_le(
+                0x6BA7_B810,
+                0x9DAD,
+                0x11D1,
+                &[0x80, 0xB4, 0x00, 0xC0, 0x4F, 0xD4, 0x30, 0xC8],
+            )
+        );
+    }
+
+    #[test]
+    fn accepts_simple_and_uppercase_content() {
+        assert_eq!(
+            UUID::parse_braced_guid("{6BA7B8109DAD11D180B400C04FD430C8}"),
+            UUID::parse_braced_guid(BRACED)
+        );
+    }
+
+    #[test]
+    fn braces_are_required() {
+        for s in [
+            "6ba7b810-9dad-11d1-80b4-00c04fd430c8",
+            "{6ba7b810-9dad-11d1-80b4-00c04fd430c8",
+            "6ba7b810-9dad-11d1-80b4-00c04fd430c8}",
+            "{{6ba7b810-9dad-11d1-80b4-00c04fd430c8}}",
+        ] {
+            assert_eq!(
+                UUID::parse_braced_guid(s),
+                Err(UuidParseError::InvalidBraces),
+                "{s}"
+            );
+        }
+    }
+
+    #[test]
+    fn content_errors_are_propagated() {
+        assert_eq!(
+            UUID::parse_braced_guid("{urn:uuid:6ba7b810-9dad-11d1-80b4-00c04fd430c8}"),
+            Err(UuidParseError::InvalidLength)
+        );
+        assert_eq!(
+            UUID::parse_braced_guid("{}"),
+            Err(UuidParseError::InvalidLength)
+        );
+    }
+}
@@ -0,0 +1,83 @@
+use crate::{UuidParseError, UUID};
+
+impl UUID {
+    /// Parses a list of UUIDs separated by commas and/or whitespace.
+    ///
+    /// Each element may use any spelling [`FromStr`](std::str::FromStr)
+    /// accepts. Empty elements, such as those produced by a trailing comma
+    /// or `", "`, are skipped, so an empty or blank input yields an empty
+    /// list.
+    ///
+    /// # Errors
+    ///
+    /// Returns the zero-based index of the first element that fails to
+    /// parse, counting only non-empty elements, together with its
+    /// [`UuidParseError`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ps_uuid::UUID;
+    ///
+    /// let list = UUID::parse_list(
+    ///     "6ba7b810-9dad-11d1-80b4-00c04fd430c8, 6ba7b811-9dad-11d1-80b4-00c04fd430c8",
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(list, [UUID::NS_DNS, UUID::NS_URL]);
+    /// ```
+    pub fn parse_list(s: &str) -> Result<Vec<Self>, (usize, UuidParseError)> {
+        s.split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|element| !element.is_empty())
+            .enumerate()
+            .map(|(index, element)| element.parse().map_err(|err| (index, err)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{UuidParseError, UUID};
+
+    const DNS: &str = "6ba7b810-9dad-11d1-80b4-00c04fd430c8";
+    const URL: &str = "6ba7b811-9dad-11d1-80b4-00c04fd430c8";
+    const OID: &str = "6ba7b812-9dad-11d1-80b4-00c04fd430c8";
+
+    #[test]
+    fn parses_comma_list() {
+        assert_eq!(
+            UUID::parse_list(&format!("{DNS},{URL},{OID},")),
+            Ok(vec![UUID::NS_DNS, UUID::NS_URL, UUID::NS_OID])
+        );
+    }
+
+    #[test]
+    fn parses_whitespace_list() {
+        assert_eq!(
+            UUID::parse_list(&format!("  {DNS}\n{URL}\t {OID}\r\n")),
+            Ok(vec![UUID::NS_DNS, UUID::NS_URL, UUID::NS_OID])
+        );
+    }
+
+    #[test]
+    fn parses_mixed_separators_and_spellings() {
+        assert_eq!(
+            UUID::parse_list(&format!("{{{DNS}}}, urn:uuid:{URL} ,")),
+            Ok(vec![UUID::NS_DNS, UUID::NS_URL])
+        );
+    }
+
+    #[test]
+    fn reports_index_of_bad_element() {
+        assert_eq!(
+            UUID::parse_list(&format!("{DNS}, ,{URL}, nope, {OID}")),
+            Err((2, UuidParseError::InvalidLength))
+        );
+    }
+
+    #[test]
+    fn blank_input_is_empty() {
+        assert_eq!(UUID::parse_list(""), Ok(vec![]));
+        assert_eq!(UUID::parse_list(" ,\n, "), Ok(vec![]));
+    }
+}
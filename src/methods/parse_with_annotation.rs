@@ -0,0 +1,147 @@
+use crate::{UuidParseError, UUID};
+
+/// Splits `<uuid> (vN)` into the UUID text and `N`, or returns `None` if the
+/// input carries no well-formed trailing annotation.
+fn split_annotation(s: &str) -> Option<(&str, u8)> {
+    let (uuid, annotation) = s.strip_suffix(')')?.rsplit_once('(')?;
+
+    let digits = annotation
+        .strip_prefix('v')
+        .or_else(|| annotation.strip_prefix('V'))?;
+
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    // The annotation must be separated from the UUID by whitespace.
+    let trimmed = uuid.trim_end();
+
+    if trimmed.len() == uuid.len() {
+        return None;
+    }
+
+    Some((trimmed, digits.parse().ok()?))
+}
+
+impl UUID {
+    /// Parses a UUID optionally followed by a version annotation, as printed
+    /// by some tools:
+    ///
+    /// ```text
+    /// 6ba7b810-9dad-11d1-80b4-00c04fd430c8 (v1)
+    /// ```
+    ///
+    /// The UUID itself may use any spelling [`FromStr`](std::str::FromStr)
+    /// accepts. The annotation, if present, must follow the UUID after
+    /// whitespace, and its version is returned alongside the parsed UUID.
+    ///
+    /// # Errors
+    ///
+    /// - Any error [`FromStr`](std::str::FromStr) returns for the UUID part.
+    /// - [`UuidParseError::VersionMismatch`] if the annotated version differs
+    ///   from the version the UUID encodes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ps_uuid::UUID;
+    ///
+    /// let (uuid, version) =
+    ///     UUID::parse_with_annotation("6ba7b810-9dad-11d1-80b4-00c04fd430c8 (v1)").unwrap();
+    ///
+    /// assert_eq!(uuid, UUID::NS_DNS);
+    /// assert_eq!(version, Some(1));
+    /// ```
+    pub fn parse_with_annotation(s: &str) -> Result<(Self, Option<u8>), UuidParseError> {
+        let Some((s, annotated)) = split_annotation(s) else {
+            return Ok((s.parse()?, None));
+        };
+
+        let uuid: Self = s.parse()?;
+        let decoded = uuid.get_version();
+
+        if decoded != Some(annotated) {
+            return Err(UuidParseError::VersionMismatch { annotated, decoded });
+        }
+
+        Ok((uuid, Some(annotated)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{UuidParseError, UUID};
+
+    const DNS: &str = "6ba7b810-9dad-11d1-80b4-00c04fd430c8";
+
+    #[test]
+    fn parses_annotated_input() {
+        assert_eq!(
+            UUID::parse_with_annotation(&format!("{DNS} (v1)")),
+            Ok((UUID::NS_DNS, Some(1)))
+        );
+    }
+
+    #[test]
+    fn parses_unannotated_input() {
+        assert_eq!(UUID::parse_with_annotation(DNS), Ok((UUID::NS_DNS, None)));
+    }
+
+    #[test]
+    fn accepts_other_spellings_and_spacing() {
+        assert_eq!(
+            UUID::parse_with_annotation(&format!("{{{DNS}}}\t(V1)")),
+            Ok((UUID::NS_DNS, Some(1)))
+        );
+        assert_eq!(
+            UUID::parse_with_annotation(&format!("urn:uuid:{DNS}   (v01)")),
+            Ok((UUID::NS_DNS, Some(1)))
+        );
+    }
+
+    #[test]
+    fn rejects_mismatched_annotation() {
+        assert_eq!(
+            UUID::parse_with_annotation(&format!("{DNS} (v4)")),
+            Err(UuidParseError::VersionMismatch {
+                annotated: 4,
+                decoded: Some(1),
+            })
+        );
+    }
+
+    #[test]
+    fn rejects_annotation_on_non_osf_uuid() {
+        assert_eq!(
+            UUID::parse_with_annotation("00000000-0000-0000-0000-000000000000 (v0)"),
+            Err(UuidParseError::VersionMismatch {
+                annotated: 0,
+                decoded: None,
+            })
+        );
+    }
+
+    #[test]
+    fn malformed_annotation_is_parsed_as_part_of_the_uuid() {
+        assert_eq!(
+            UUID::parse_with_annotation(&format!("{DNS} (version 1)")),
+            Err(UuidParseError::InvalidLength)
+        );
+        assert_eq!(
+            UUID::parse_with_annotation(&format!("{DNS}(v1)")),
+            Err(UuidParseError::InvalidLength)
+        );
+        assert_eq!(
+            UUID::parse_with_annotation(&format!("{DNS} (v)")),
+            Err(UuidParseError::InvalidLength)
+        );
+    }
+
+    #[test]
+    fn propagates_uuid_parse_errors() {
+        assert_eq!(
+            UUID::parse_with_annotation("not-a-uuid (v1)"),
+            Err(UuidParseError::InvalidLength)
+        );
+    }
+}
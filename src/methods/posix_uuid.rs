@@ -0,0 +1,50 @@
+//! The POSIX `uuid_t` layout for FFI.
+
+use crate::{UUID, UUID_BYTES};
+
+/// A UUID laid out as the POSIX `uuid_t` of libuuid and the BSDs: 16 bytes
+/// in network (big-endian) order.
+///
+/// This is the byte order [`UUID`] itself uses, so the conversions copy the
+/// bytes unchanged; the wrapper documents the intent at an FFI boundary and
+/// guarantees the `#[repr(C)]` layout. See [`WindowsGuid`](crate::WindowsGuid)
+/// for the Windows `GUID` structure.
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(C)]
+pub struct PosixUuid(pub [u8; UUID_BYTES]);
+
+impl From<UUID> for PosixUuid {
+    #[inline]
+    fn from(uuid: UUID) -> Self {
+        Self(uuid.bytes)
+    }
+}
+
+impl From<PosixUuid> for UUID {
+    #[inline]
+    fn from(posix: PosixUuid) -> Self {
+        Self::from_bytes(posix.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::mem::{align_of, size_of};
+
+    use super::PosixUuid;
+    use crate::UUID;
+
+    #[test]
+    fn preserves_byte_order() {
+        let posix = PosixUuid::from(UUID::NS_DNS);
+
+        assert_eq!(&posix.0, UUID::NS_DNS.as_bytes());
+        assert_eq!(UUID::from(posix), UUID::NS_DNS);
+    }
+
+    #[test]
+    fn matches_the_uuid_t_layout() {
+        assert_eq!(size_of::<PosixUuid>(), 16);
+        assert_eq!(align_of::<PosixUuid>(), 1);
+    }
+}
@@ -0,0 +1,62 @@
+use super::gen_v7_monotonic::reset_v7_monotonic;
+use crate::{STATE, UUID};
+
+impl UUID {
+    /// Reinitializes the process-wide [`STATE`] with a fresh random node ID
+    /// and clock sequences; see [`State::reset`]. The lock-free sequence of
+    /// [`UUID::gen_v7_monotonic`] is cleared as well, so it follows the clock
+    /// again even if it was run ahead, e.g. by a far-future floor; its 62
+    /// random bits keep the UUIDs it issues distinct.
+    ///
+    /// Generation never needs this to recover from a panic, because
+    /// [`STATE`] is a `parking_lot` mutex, which does not poison. It is meant
+    /// for use after a detected anomaly, such as a suspected duplicate, or
+    /// in a child process after `fork()`.
+    ///
+    /// [`State::reset`]: crate::State::reset
+    pub fn reset_global_state() {
+        STATE.lock().reset();
+        reset_v7_monotonic();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    use crate::UUID;
+
+    #[test]
+    fn generation_continues_after_a_reset() {
+        // The node drawn here is not asserted: other tests may replace the
+        // global node concurrently. `State::reset` is tested on a private
+        // state.
+        UUID::reset_global_state();
+
+        let uuid = UUID::gen_v1().expect("generation must succeed after a reset");
+
+        assert_eq!(uuid.get_version(), Some(1));
+    }
+
+    #[test]
+    fn reset_releases_a_pinned_v7_sequence() {
+        // Further ahead than any floor other tests pass, so no concurrent
+        // caller can pin the sequence this far.
+        let far = SystemTime::now() + Duration::from_secs(86_400);
+        let floor = UUID::new_v7(
+            far.duration_since(UNIX_EPOCH).expect("after the epoch"),
+            [0; 8],
+        );
+
+        let pinned = UUID::gen_v7_monotonic_since(&floor).expect("generation must succeed");
+
+        assert!(pinned > floor);
+
+        UUID::reset_global_state();
+
+        let released = UUID::gen_v7_monotonic().expect("generation must succeed");
+
+        assert!(released < floor, "the sequence must follow the clock again");
+    }
+}
@@ -0,0 +1,109 @@
+use std::cmp::Reverse;
+
+use crate::UUID;
+
+impl UUID {
+    /// Sorts `ids` in place by embedded timestamp, earliest first.
+    ///
+    /// Timestamps are decoded with [`UUID::get_timestamp`], so v1, v6 and v7
+    /// UUIDs interleave by the instant they encode rather than by their
+    /// bytes. UUIDs without a timestamp (such as versions 3, 4, 5, and 8)
+    /// are moved to the end. Ties, and the trailing UUIDs without a
+    /// timestamp, are ordered by their bytes, so the result does not depend
+    /// on the input order.
+    ///
+    /// Each timestamp is decoded once, not once per comparison.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use ps_uuid::UUID;
+    ///
+    /// let later = UUID::new_v7(Duration::from_secs(20), [0; 8]);
+    /// let earlier = UUID::new_v7(Duration::from_secs(10), [0; 8]);
+    /// let random = UUID::gen_v4();
+    ///
+    /// let mut ids = [random, later, earlier];
+    ///
+    /// UUID::sort_by_time(&mut ids);
+    ///
+    /// assert_eq!(ids, [earlier, later, random]);
+    /// ```
+    pub fn sort_by_time(ids: &mut [Self]) {
+        // `Reverse` on the `Option` alone would also reverse the order of the
+        // timestamps, so rank the presence of a timestamp separately.
+        ids.sort_by_cached_key(|id| {
+            let timestamp = id.get_timestamp();
+
+            (Reverse(timestamp.is_some()), timestamp, *id)
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use crate::UUID;
+
+    #[test]
+    fn mixed_versions_sort_chronologically_with_v4_trailing() {
+        let at = |secs| UNIX_EPOCH + Duration::from_secs(secs);
+        let node = [1, 2, 3, 4, 5, 6];
+
+        let v1 = UUID::new_v1(at(1_000), 0, node).expect("valid v1 timestamp");
+        let v6 = UUID::new_v6(at(2_000), 0, node).expect("valid v6 timestamp");
+        let v7 = UUID::new_v7(Duration::from_secs(3_000), [0; 8]);
+        let v1_late = UUID::new_v1(at(4_000), 0, node).expect("valid v1 timestamp");
+
+        let mut v4s = [UUID::gen_v4(), UUID::gen_v4(), UUID::gen_v4()];
+        v4s.sort();
+
+        let mut ids = [v4s[2], v1_late, v7, v4s[0], v6, v4s[1], v1];
+
+        UUID::sort_by_time(&mut ids);
+
+        assert_eq!(ids, [v1, v6, v7, v1_late, v4s[0], v4s[1], v4s[2]]);
+    }
+
+    #[test]
+    fn order_is_by_time_not_bytes() {
+        // v1 stores the low timestamp bits first, so a UUID issued 2³² − 1
+        // ticks later has a smaller `time_low` and sorts first by bytes.
+        let node = [0; 6];
+        let t = UNIX_EPOCH + Duration::from_secs(1_000);
+        let earlier = UUID::new_v1(t, 0, node).expect("valid v1 timestamp");
+        let later = UUID::new_v1(t + Duration::from_nanos(429_496_729_500), 0, node)
+            .expect("valid v1 timestamp");
+
+        assert!(earlier > later);
+
+        let mut ids = [later, earlier];
+
+        UUID::sort_by_time(&mut ids);
+
+        assert_eq!(ids, [earlier, later]);
+    }
+
+    #[test]
+    fn ties_are_ordered_by_bytes() {
+        let a = UUID::new_v7(Duration::from_secs(5), [0x00; 8]);
+        let b = UUID::new_v7(Duration::from_secs(5), [0xFF; 8]);
+
+        let mut ids = [b, a];
+
+        UUID::sort_by_time(&mut ids);
+
+        assert_eq!(ids, [a, b]);
+    }
+
+    #[test]
+    fn empty_slice_is_a_no_op() {
+        let mut ids: [UUID; 0] = [];
+
+        UUID::sort_by_time(&mut ids);
+    }
+}
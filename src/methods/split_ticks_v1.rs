@@ -0,0 +1,53 @@
+use crate::UUID;
+
+impl UUID {
+    /// Splits a 60-bit Gregorian tick count into the version-1 wire fields
+    /// `(time_low, time_mid, time_hi)`, as [`UUID::from_parts_v1`] takes them.
+    ///
+    /// `time_low` holds the least-significant 32 bits, `time_mid` the next
+    /// 16, and `time_hi` the most-significant 12. Bits above the 60th are
+    /// discarded.
+    #[must_use]
+    pub const fn split_ticks_v1(ticks: u64) -> (u32, u16, u16) {
+        let time_low = (ticks & 0xFFFF_FFFF) as u32;
+        let time_mid = ((ticks >> 32) & 0xFFFF) as u16;
+        let time_hi = ((ticks >> 48) & 0x0FFF) as u16;
+
+        (time_low, time_mid, time_hi)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::UUID;
+
+    #[test]
+    fn matches_the_hand_computed_split() {
+        let ticks = 0x0123_4567_89AB_CDEF;
+
+        assert_eq!(
+            UUID::split_ticks_v1(ticks),
+            (
+                (ticks & 0xFFFF_FFFF) as u32,
+                ((ticks >> 32) & 0xFFFF) as u16,
+                ((ticks >> 48) & 0x0FFF) as u16,
+            )
+        );
+        assert_eq!(UUID::split_ticks_v1(ticks), (0x89AB_CDEF, 0x4567, 0x0123));
+    }
+
+    #[test]
+    fn bits_above_the_60th_are_discarded() {
+        assert_eq!(UUID::split_ticks_v1(u64::MAX), (u32::MAX, u16::MAX, 0x0FFF));
+        assert_eq!(UUID::split_ticks_v1(0xF000_0000_0000_0000), (0, 0, 0));
+    }
+
+    #[test]
+    fn round_trips_through_the_encoded_timestamp() {
+        let ticks = 0x01EE_833B_04AF_C000;
+        let (time_low, time_mid, time_hi) = UUID::split_ticks_v1(ticks);
+        let uuid = UUID::from_parts_v1(time_low, time_mid, time_hi, 0x2A3B, [0; 6]);
+
+        assert_eq!(uuid.to_string(), "04afc000-833b-11ee-aa3b-000000000000");
+    }
+}
@@ -0,0 +1,57 @@
+use crate::UUID;
+
+impl UUID {
+    /// Splits a 60-bit Gregorian tick count into the version-6 wire fields
+    /// `(time_high, time_mid, time_low)`, as [`UUID::from_parts_v6`] takes
+    /// them.
+    ///
+    /// `time_high` holds the most-significant 32 bits, `time_mid` the next
+    /// 16, and `time_low` the least-significant 12, so the fields sort in
+    /// timestamp order. Bits above the 60th are discarded.
+    #[must_use]
+    pub const fn split_ticks_v6(ticks: u64) -> (u32, u16, u16) {
+        let time_high = ((ticks >> 28) & 0xFFFF_FFFF) as u32;
+        let time_mid = ((ticks >> 12) & 0xFFFF) as u16;
+        let time_low = (ticks & 0x0FFF) as u16;
+
+        (time_high, time_mid, time_low)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::UUID;
+
+    #[test]
+    fn matches_the_hand_computed_split() {
+        let ticks = 0x0123_4567_89AB_CDEF;
+
+        assert_eq!(
+            UUID::split_ticks_v6(ticks),
+            (
+                ((ticks >> 28) & 0xFFFF_FFFF) as u32,
+                ((ticks >> 12) & 0xFFFF) as u16,
+                (ticks & 0x0FFF) as u16,
+            )
+        );
+        assert_eq!(UUID::split_ticks_v6(ticks), (0x1234_5678, 0x9ABC, 0x0DEF));
+    }
+
+    #[test]
+    fn bits_above_the_60th_are_discarded() {
+        assert_eq!(UUID::split_ticks_v6(u64::MAX), (u32::MAX, u16::MAX, 0x0FFF));
+        assert_eq!(UUID::split_ticks_v6(0xF000_0000_0000_0000), (0, 0, 0));
+    }
+
+    #[test]
+    fn agrees_with_the_v1_split() {
+        let ticks = 0x01EE_833B_04AF_C000;
+        let (time_high, time_mid, time_low) = UUID::split_ticks_v6(ticks);
+        let v6 = UUID::from_parts_v6(time_high, time_mid, time_low, 0, [0; 6]);
+
+        let (time_low, time_mid, time_hi) = UUID::split_ticks_v1(ticks);
+        let v1 = UUID::from_parts_v1(time_low, time_mid, time_hi, 0, [0; 6]);
+
+        assert_eq!(v6.get_timestamp(), v1.get_timestamp());
+    }
+}
@@ -0,0 +1,153 @@
+//! SQL Server `uniqueidentifier` ordering for UUID.
+
+use core::cmp::Ordering;
+
+use crate::UUID;
+
+/// The order in which SQL Server compares the bytes of a `uniqueidentifier`,
+/// most significant first, as indices into [`UUID::as_bytes`].
+const SQL_SERVER_ORDER: [usize; 16] = [10, 11, 12, 13, 14, 15, 8, 9, 7, 6, 5, 4, 3, 2, 1, 0];
+
+/// A UUID that sorts the way SQL Server sorts `uniqueidentifier` values.
+///
+/// SQL Server compares the five hyphen-separated groups of the canonical
+/// string form from last to first, so a clustered index on a
+/// `uniqueidentifier` column is ordered by:
+///
+/// 1. the 6-byte node group (`xxxxxxxx-xxxx-xxxx-xxxx-NNNNNNNNNNNN`),
+///    left to right;
+/// 2. the 2-byte clock-sequence group (`…-NNNN-…`), left to right;
+/// 3. the third group (`…-xxxx-NNNN-…`), right to left;
+/// 4. the second group (`…-NNNN-xxxx-…`), right to left;
+/// 5. the first group (`NNNNNNNN-…`), right to left.
+///
+/// The last three groups are compared right to left because SQL Server
+/// stores them little-endian and compares the stored bytes in order.
+///
+/// Sorting a collection of `SqlServerOrdered` therefore matches the order the
+/// database returns for `ORDER BY` on the same values. Equality and hashing
+/// are those of the wrapped UUID.
+///
+/// ```
+/// use ps_uuid::{SqlServerOrdered, UUID};
+///
+/// let low: UUID = "ffffffff-ffff-ffff-ffff-000000000000".parse().unwrap();
+/// let high: UUID = "00000000-0000-0000-0000-000000000001".parse().unwrap();
+///
+/// assert!(low > high);
+/// assert!(SqlServerOrdered(low) < SqlServerOrdered(high));
+/// ```
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct SqlServerOrdered(pub UUID);
+
+impl Ord for SqlServerOrdered {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let (a, b) = (self.0.as_bytes(), other.0.as_bytes());
+
+        SQL_SERVER_ORDER
+            .iter()
+            .map(|&i| a[i].cmp(&b[i]))
+            .find(|ordering| ordering.is_ne())
+            .unwrap_or(Ordering::Equal)
+    }
+}
+
+impl PartialOrd for SqlServerOrdered {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl From<UUID> for SqlServerOrdered {
+    #[inline]
+    fn from(uuid: UUID) -> Self {
+        Self(uuid)
+    }
+}
+
+impl From<SqlServerOrdered> for UUID {
+    #[inline]
+    fn from(ordered: SqlServerOrdered) -> Self {
+        ordered.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+    use super::SqlServerOrdered;
+    use crate::UUID;
+
+    /// Values in ascending SQL Server order, as returned by
+    /// `SELECT … ORDER BY` on a `uniqueidentifier` column.
+    const SQL_SERVER_SORTED: [&str; 17] = [
+        "01000000-0000-0000-0000-000000000000",
+        "10000000-0000-0000-0000-000000000000",
+        "00010000-0000-0000-0000-000000000000",
+        "00100000-0000-0000-0000-000000000000",
+        "00000100-0000-0000-0000-000000000000",
+        "00001000-0000-0000-0000-000000000000",
+        "00000001-0000-0000-0000-000000000000",
+        "00000010-0000-0000-0000-000000000000",
+        "00000000-0100-0000-0000-000000000000",
+        "00000000-1000-0000-0000-000000000000",
+        "00000000-0001-0000-0000-000000000000",
+        "00000000-0010-0000-0000-000000000000",
+        "00000000-0000-0100-0000-000000000000",
+        "00000000-0000-1000-0000-000000000000",
+        "00000000-0000-0001-0000-000000000000",
+        "00000000-0000-0010-0000-000000000000",
+        "00000000-0000-0000-0100-000000000000",
+    ];
+
+    const NODE_SORTED: [&str; 5] = [
+        "00000000-0000-0000-1000-000000000000",
+        "00000000-0000-0000-0000-000000000001",
+        "00000000-0000-0000-0000-000000000010",
+        "00000000-0000-0000-0000-010000000000",
+        "00000000-0000-0000-0000-100000000000",
+    ];
+
+    fn parse_all(strings: &[&str]) -> Vec<SqlServerOrdered> {
+        strings
+            .iter()
+            .map(|s| SqlServerOrdered(s.parse().expect("valid UUID")))
+            .collect()
+    }
+
+    #[test]
+    fn sort_matches_sql_server() {
+        for expected in [
+            parse_all(&SQL_SERVER_SORTED),
+            parse_all(&NODE_SORTED),
+            parse_all(&[SQL_SERVER_SORTED[16], NODE_SORTED[0]]),
+        ] {
+            let mut sorted = expected.clone();
+
+            sorted.reverse();
+            sorted.sort();
+
+            assert_eq!(sorted, expected);
+        }
+    }
+
+    #[test]
+    fn equality_follows_the_wrapped_uuid() {
+        let uuid = UUID::gen_v4();
+
+        assert_eq!(SqlServerOrdered(uuid), SqlServerOrdered::from(uuid));
+        assert_eq!(
+            SqlServerOrdered(uuid).cmp(&SqlServerOrdered(uuid)),
+            core::cmp::Ordering::Equal
+        );
+        assert_eq!(UUID::from(SqlServerOrdered(uuid)), uuid);
+    }
+
+    #[test]
+    fn nil_and_max_are_extremes() {
+        let uuid = SqlServerOrdered(UUID::gen_v4());
+
+        assert!(SqlServerOrdered(UUID::nil()) <= uuid);
+        assert!(uuid <= SqlServerOrdered(UUID::max()));
+    }
+}
@@ -0,0 +1,95 @@
+use std::time::{Duration, UNIX_EPOCH};
+
+use crate::UUID;
+
+impl UUID {
+    /// Returns the index of the `window`-sized time bucket this UUID's
+    /// embedded timestamp falls into, counted from the Unix epoch.
+    ///
+    /// The index is `floor(timestamp_since_unix / window)`, so UUIDs whose
+    /// timestamps share a window share a bucket. The timestamp is decoded by
+    /// [`UUID::get_timestamp`] and inherits its resolution (whole
+    /// milliseconds for version 7, about 429 seconds for version 2).
+    ///
+    /// Returns `None` if the UUID does not encode a timestamp, if the
+    /// timestamp precedes the Unix epoch, if `window` is zero, or if the
+    /// index does not fit in a `u64`.
+    #[must_use]
+    pub fn time_bucket(&self, window: Duration) -> Option<u64> {
+        if window.is_zero() {
+            return None;
+        }
+
+        let since_unix = self.get_timestamp()?.duration_since(UNIX_EPOCH).ok()?;
+
+        u64::try_from(since_unix.as_nanos() / window.as_nanos()).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    use crate::UUID;
+
+    const MINUTE: Duration = Duration::from_secs(60);
+
+    fn v7_at(offset: Duration) -> UUID {
+        // 2023-11-14T22:13:20Z, 20 seconds into its minute.
+        UUID::new_v7(Duration::from_secs(1_700_000_000) + offset, [0; 8])
+    }
+
+    #[test]
+    fn thirty_seconds_apart_share_a_minute_bucket() {
+        let a = v7_at(Duration::ZERO);
+        let b = v7_at(Duration::from_secs(30));
+
+        assert_eq!(a.time_bucket(MINUTE), b.time_bucket(MINUTE));
+    }
+
+    #[test]
+    fn ninety_seconds_apart_fall_in_different_minute_buckets() {
+        let a = v7_at(Duration::ZERO);
+        let b = v7_at(Duration::from_secs(90));
+
+        assert_ne!(a.time_bucket(MINUTE), b.time_bucket(MINUTE));
+    }
+
+    #[test]
+    fn bucket_is_floor_of_timestamp_over_window() {
+        let uuid = v7_at(Duration::ZERO);
+
+        assert_eq!(uuid.time_bucket(MINUTE), Some(1_700_000_000 / 60));
+        assert_eq!(
+            uuid.time_bucket(Duration::from_millis(1)),
+            Some(1_700_000_000_000)
+        );
+    }
+
+    #[test]
+    fn zero_window_yields_none() {
+        assert_eq!(v7_at(Duration::ZERO).time_bucket(Duration::ZERO), None);
+    }
+
+    #[test]
+    fn untimed_uuid_yields_none() {
+        assert_eq!(UUID::gen_v4().time_bucket(MINUTE), None);
+    }
+
+    #[test]
+    fn pre_unix_timestamp_yields_none() {
+        let before_unix = UNIX_EPOCH - Duration::from_secs(3600);
+        let uuid = UUID::new_v1(before_unix, 0, [0; 6]).expect("new_v1 should succeed");
+
+        assert_eq!(uuid.time_bucket(MINUTE), None);
+    }
+
+    #[test]
+    fn v1_uuids_are_bucketed() {
+        let time = SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        let uuid = UUID::new_v1(time, 0, [0; 6]).expect("new_v1 should succeed");
+
+        assert_eq!(uuid.time_bucket(MINUTE), Some(1_700_000_000 / 60));
+    }
+}
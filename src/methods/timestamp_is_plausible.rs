@@ -0,0 +1,83 @@
+use std::time::{Duration, SystemTime};
+
+use crate::UUID;
+
+impl UUID {
+    /// Checks that the embedded timestamp does not lie in the future beyond
+    /// `tolerance`, as judged against `now`.
+    ///
+    /// Every decodable timestamp lies at or after the epoch of its version,
+    /// so the timestamp is plausible exactly when it is no later than
+    /// `now + tolerance`. A UUID far in the future usually signals corrupt
+    /// input or a UUID misidentified as time-based. If `now + tolerance`
+    /// overflows the platform clock, every timestamp is plausible.
+    ///
+    /// Returns `None` if the UUID does not encode a timestamp (see
+    /// [`UUID::get_timestamp`]).
+    #[must_use]
+    pub fn timestamp_is_plausible(&self, now: SystemTime, tolerance: Duration) -> Option<bool> {
+        let timestamp = self.get_timestamp()?;
+
+        Some(
+            now.checked_add(tolerance)
+                .is_none_or(|limit| timestamp <= limit),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    use crate::UUID;
+
+    const TOLERANCE: Duration = Duration::from_secs(60);
+
+    #[test]
+    fn current_v7_is_plausible() {
+        let uuid = UUID::gen_v7().expect("generation must succeed");
+
+        assert_eq!(
+            uuid.timestamp_is_plausible(SystemTime::now(), TOLERANCE),
+            Some(true)
+        );
+    }
+
+    #[test]
+    fn year_9999_v7_is_implausible() {
+        // 9999-01-01T00:00:00Z
+        let uuid = UUID::new_v7(Duration::from_secs(253_370_764_800), [0; 8]);
+
+        assert_eq!(
+            uuid.timestamp_is_plausible(SystemTime::now(), TOLERANCE),
+            Some(false)
+        );
+    }
+
+    #[test]
+    fn tolerance_is_inclusive() {
+        let now = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        let at_limit = UUID::new_v7(Duration::from_secs(1_700_000_060), [0; 8]);
+        let past_limit = UUID::new_v7(Duration::from_millis(1_700_000_060_001), [0; 8]);
+
+        assert_eq!(at_limit.timestamp_is_plausible(now, TOLERANCE), Some(true));
+        assert_eq!(
+            past_limit.timestamp_is_plausible(now, TOLERANCE),
+            Some(false)
+        );
+    }
+
+    #[test]
+    fn uuids_without_timestamp_yield_none() {
+        assert_eq!(
+            UUID::gen_v4().timestamp_is_plausible(SystemTime::now(), TOLERANCE),
+            None
+        );
+        assert_eq!(
+            UUID::new_v5(&UUID::NS_DNS, "example.com")
+                .timestamp_is_plausible(SystemTime::now(), TOLERANCE),
+            None
+        );
+    }
+}
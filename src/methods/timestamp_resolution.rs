@@ -0,0 +1,99 @@
+use std::time::Duration;
+
+use crate::{Variant, UUID};
+
+impl UUID {
+    /// Returns the granularity of the timestamp [`UUID::get_timestamp`]
+    /// decodes, or `None` if the UUID does not encode one.
+    ///
+    /// | UUID             | Resolution                        |
+    /// |------------------|-----------------------------------|
+    /// | versions 1 and 6 | 100 ns                            |
+    /// | version 2        | 2³² × 100 ns (≈ 429.5 s)          |
+    /// | version 7        | 1 ms                              |
+    /// | DCOM variant     | 100 ns (`FILETIME`)               |
+    /// | NCS variant      | 4 µs                              |
+    ///
+    /// Version 2 encodes 100 ns ticks, but its low 32 timestamp bits are
+    /// overwritten by the local ID, so only the coarser resolution survives.
+    /// Version 7 may carry a sub-millisecond fraction, which is not decoded
+    /// (see [`UUID::get_timestamp`]).
+    #[must_use]
+    pub const fn timestamp_resolution(&self) -> Option<Duration> {
+        match (self.get_version(), self.get_variant()) {
+            (Some(1 | 6), Variant::OSF) | (_, Variant::DCOM) => Some(Duration::from_nanos(100)),
+            (Some(2), Variant::OSF) => Some(Duration::from_nanos(429_496_729_600)),
+            (Some(7), Variant::OSF) => Some(Duration::from_millis(1)),
+            (_, Variant::NCS) => Some(Duration::from_micros(4)),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+    use std::time::Duration;
+
+    use crate::UUID;
+
+    #[test]
+    fn v1_and_v6_resolve_100_ns() {
+        let v1 = UUID::gen_v1().expect("generation must succeed");
+        let v6 = UUID::gen_v6().expect("generation must succeed");
+
+        assert_eq!(v1.timestamp_resolution(), Some(Duration::from_nanos(100)));
+        assert_eq!(v6.timestamp_resolution(), Some(Duration::from_nanos(100)));
+    }
+
+    #[test]
+    fn v2_resolves_the_surviving_high_bits() {
+        let v2 = UUID::gen_v2(0, 1000).expect("generation must succeed");
+
+        assert_eq!(
+            v2.timestamp_resolution(),
+            Some(Duration::from_nanos(100 << 32))
+        );
+    }
+
+    #[test]
+    fn v7_resolves_1_ms() {
+        let v7 = UUID::gen_v7().expect("generation must succeed");
+
+        assert_eq!(v7.timestamp_resolution(), Some(Duration::from_millis(1)));
+    }
+
+    #[test]
+    fn ncs_resolves_4_us() {
+        let ncs = UUID::from_parts_ncs(&[1, 2, 3, 4, 5, 6], 2, &[0; 7]);
+
+        assert_eq!(ncs.timestamp_resolution(), Some(Duration::from_micros(4)));
+    }
+
+    #[test]
+    fn dcom_resolves_100_ns() {
+        let dcom = UUID::NS_DNS.with_variant(crate::Variant::DCOM);
+
+        assert_eq!(dcom.timestamp_resolution(), Some(Duration::from_nanos(100)));
+    }
+
+    #[test]
+    fn agrees_with_get_timestamp_on_presence() {
+        let samples = [
+            UUID::gen_v4(),
+            UUID::new_v5(&UUID::NS_DNS, "a"),
+            UUID::nil(),
+            UUID::max(),
+            UUID::NS_URL,
+            UUID::gen_v4().with_version(8),
+        ];
+
+        for uuid in samples {
+            assert_eq!(
+                uuid.timestamp_resolution().is_some(),
+                uuid.get_timestamp().is_some(),
+                "{uuid}"
+            );
+        }
+    }
+}
@@ -0,0 +1,81 @@
+use std::time::SystemTime;
+
+use crate::UUID;
+
+impl UUID {
+    /// Returns the earliest and latest embedded timestamps across `ids`, or
+    /// `None` if none of them carries one.
+    ///
+    /// Timestamps are decoded with [`UUID::get_timestamp`], so UUIDs it
+    /// returns `None` for (such as versions 3, 4, 5, and 8) are skipped,
+    /// while NCS-variant UUIDs, including the nil UUID, do count.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::time::{Duration, UNIX_EPOCH};
+    ///
+    /// use ps_uuid::UUID;
+    ///
+    /// let ids = [
+    ///     UUID::new_v7(Duration::from_secs(20), [0; 8]),
+    ///     UUID::gen_v4(),
+    ///     UUID::new_v7(Duration::from_secs(10), [0; 8]),
+    /// ];
+    ///
+    /// assert_eq!(
+    ///     UUID::timestamp_span(&ids),
+    ///     Some((UNIX_EPOCH + Duration::from_secs(10), UNIX_EPOCH + Duration::from_secs(20)))
+    /// );
+    /// ```
+    #[must_use]
+    pub fn timestamp_span(ids: &[Self]) -> Option<(SystemTime, SystemTime)> {
+        ids.iter()
+            .filter_map(Self::get_timestamp)
+            .fold(None, |span, t| match span {
+                None => Some((t, t)),
+                Some((min, max)) => Some((min.min(t), max.max(t))),
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use crate::UUID;
+
+    #[test]
+    fn spans_a_batch_of_v7_uuids() {
+        let start = Duration::from_millis(1_700_000_000_123);
+        let ids: Vec<UUID> = [7, 3, 1500, 0, 512]
+            .into_iter()
+            .map(|offset| UUID::new_v7(start + Duration::from_millis(offset), [0xAB; 8]))
+            .collect();
+
+        assert_eq!(
+            UUID::timestamp_span(&ids),
+            Some((
+                UNIX_EPOCH + start,
+                UNIX_EPOCH + start + Duration::from_millis(1500)
+            ))
+        );
+    }
+
+    #[test]
+    fn single_timestamp_is_both_ends() {
+        let id = UUID::new_v7(Duration::from_secs(1), [0; 8]);
+        let t = UNIX_EPOCH + Duration::from_secs(1);
+
+        assert_eq!(UUID::timestamp_span(&[UUID::gen_v4(), id]), Some((t, t)));
+    }
+
+    #[test]
+    fn no_timestamps_yield_none() {
+        assert_eq!(UUID::timestamp_span(&[]), None);
+        assert_eq!(
+            UUID::timestamp_span(&[UUID::gen_v4(), UUID::new_v5(&UUID::NS_DNS, "x")]),
+            None
+        );
+    }
+}
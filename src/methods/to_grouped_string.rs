@@ -0,0 +1,83 @@
+use crate::{helpers::LUT, UUID};
+
+/// The byte ranges of the 8-4-4-4-12 groups.
+const GROUPS: [(usize, usize); 5] = [(0, 4), (4, 6), (6, 8), (8, 10), (10, 16)];
+
+/// Writes the 8-4-4-4-12 grouping of `uuid` with the digits of `lut` and
+/// `sep` between the groups.
+pub(crate) fn grouped(uuid: &UUID, sep: char, lut: &[u8; 16]) -> String {
+    let mut out = String::with_capacity(32 + 4 * sep.len_utf8());
+
+    for (index, (start, end)) in GROUPS.into_iter().enumerate() {
+        if index > 0 {
+            out.push(sep);
+        }
+
+        for &byte in &uuid.bytes[start..end] {
+            out.push(lut[usize::from(byte >> 4)] as char);
+            out.push(lut[usize::from(byte & 0x0F)] as char);
+        }
+    }
+
+    out
+}
+
+impl UUID {
+    /// Returns the lowercase 8-4-4-4-12 grouping with `sep` in place of each
+    /// hyphen, for interfaces that display UUIDs with spaces, underscores
+    /// or similar.
+    ///
+    /// `to_grouped_string('-')` equals the [`Display`](std::fmt::Display)
+    /// form.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ps_uuid::UUID;
+    ///
+    /// assert_eq!(
+    ///     UUID::NS_DNS.to_grouped_string(' '),
+    ///     "6ba7b810 9dad 11d1 80b4 00c04fd430c8"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn to_grouped_string(&self, sep: char) -> String {
+        grouped(self, sep, LUT)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+    use crate::UUID;
+
+    #[test]
+    fn replaces_all_four_separators() {
+        let uuid = UUID::gen_v4();
+        let grouped = uuid.to_grouped_string('_');
+
+        assert_eq!(grouped.matches('_').count(), 4);
+        assert!(!grouped.contains('-'));
+        assert_eq!(grouped, uuid.to_string().replace('-', "_"));
+
+        let parsed: UUID = grouped.replace('_', "-").parse().expect("valid UUID");
+
+        assert_eq!(parsed, uuid);
+    }
+
+    #[test]
+    fn hyphen_matches_display() {
+        assert_eq!(
+            UUID::NS_DNS.to_grouped_string('-'),
+            UUID::NS_DNS.to_string()
+        );
+    }
+
+    #[test]
+    fn multibyte_separators_are_supported() {
+        assert_eq!(
+            UUID::max().to_grouped_string('·'),
+            "ffffffff·ffff·ffff·ffff·ffffffffffff"
+        );
+    }
+}
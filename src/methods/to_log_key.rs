@@ -0,0 +1,96 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::UUID;
+
+/// The prefix width: enough digits for every millisecond count a UUID can
+/// encode, the largest being a DCOM `FILETIME` near 60056 AD.
+const PREFIX_DIGITS: usize = 16;
+
+impl UUID {
+    /// Returns a key for logs and event streams whose lexicographic order is
+    /// chronological: a 16-digit, zero-padded Unix millisecond timestamp, a
+    /// hyphen, and the 32-digit simple form of the UUID.
+    ///
+    /// ```text
+    /// 0001700000000000-018bcfe568007000800000000000ffff
+    /// ```
+    ///
+    /// The timestamp is the one [`UUID::get_timestamp`] decodes, or the
+    /// current time for UUIDs that carry none, so such keys sort by when
+    /// they were made. Timestamps before 1970 are clamped to zero. Keys
+    /// with the same millisecond fall back to the UUID's byte order.
+    #[must_use]
+    pub fn to_log_key(&self) -> String {
+        let timestamp = self.get_timestamp().unwrap_or_else(SystemTime::now);
+        let ms = timestamp
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |since_unix| since_unix.as_millis());
+
+        format!("{ms:0PREFIX_DIGITS$}-{}", self.simple())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    use crate::UUID;
+
+    #[test]
+    fn has_a_fixed_width_prefix() {
+        let uuid = UUID::from_parts_v7(1_700_000_000_000, 0, 0xFFFF);
+
+        assert_eq!(
+            uuid.to_log_key(),
+            "0001700000000000-018bcfe568007000800000000000ffff"
+        );
+    }
+
+    #[test]
+    fn keys_a_second_apart_sort_as_strings() {
+        let node = [1, 2, 3, 4, 5, 6];
+        let t = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+
+        let earlier = UUID::new_v1(t, 0, node).expect("new_v1 should succeed");
+        let later =
+            UUID::new_v1(t + Duration::from_secs(1), 0, node).expect("new_v1 should succeed");
+
+        assert!(earlier.to_log_key() < later.to_log_key());
+
+        let v7_earlier = UUID::new_v7(Duration::from_secs(9), [0xFF; 8]);
+        let v7_later = UUID::new_v7(Duration::from_secs(10), [0x00; 8]);
+
+        assert!(v7_earlier.to_log_key() < v7_later.to_log_key());
+    }
+
+    #[test]
+    fn untimed_uuids_use_the_current_time() {
+        let before = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("after the epoch")
+            .as_millis();
+        let key = UUID::gen_v4().to_log_key();
+        let after = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("after the epoch")
+            .as_millis();
+
+        let (prefix, simple) = key.split_once('-').expect("key has a hyphen");
+        let ms: u128 = prefix.parse().expect("prefix is decimal");
+
+        assert_eq!(prefix.len(), 16);
+        assert_eq!(simple.len(), 32);
+        assert!((before..=after).contains(&ms));
+    }
+
+    #[test]
+    fn pre_unix_timestamps_clamp_to_zero() {
+        let t = UNIX_EPOCH - Duration::from_secs(315_532_800);
+        let key = UUID::new_v1(t, 0, [0; 6])
+            .expect("new_v1 should succeed")
+            .to_log_key();
+
+        assert!(key.starts_with("0000000000000000-"), "{key}");
+    }
+}
@@ -0,0 +1,82 @@
+use crate::UUID;
+
+/// The `ShortUUID` base-57 alphabet: the alphanumerics without the easily
+/// confused `0`, `1`, `I`, `O`, and `l`.
+pub(super) const SHORT_ALPHABET: &[u8; 57] =
+    b"23456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+/// The number of base-57 digits needed for any 128-bit value.
+pub(super) const SHORT_LEN: usize = 22;
+
+impl UUID {
+    /// Encodes this UUID in the 22-character base-57 form used by the
+    /// `ShortUUID` libraries.
+    ///
+    /// The UUID's big-endian 128-bit value is written most significant digit
+    /// first in the alphabet
+    /// `23456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz`, which
+    /// omits the easily confused `0`, `1`, `I`, `O`, and `l`, and is
+    /// left-padded with `2` (the zero digit) to 22 characters. See
+    /// [`UUID::from_short`] for the inverse.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ps_uuid::UUID;
+    ///
+    /// let uuid: UUID = "3b1f8b40-222c-4a6e-b77e-779d5a94e21c".parse().unwrap();
+    ///
+    /// assert_eq!(uuid.to_short(), "CXc85b4rqinB7s5J52TRYb");
+    /// ```
+    #[must_use]
+    pub fn to_short(&self) -> String {
+        let mut value = self.to_u128();
+        let mut digits = [SHORT_ALPHABET[0]; SHORT_LEN];
+
+        for digit in digits.iter_mut().rev() {
+            *digit = SHORT_ALPHABET[(value % 57) as usize];
+            value /= 57;
+        }
+
+        digits.iter().map(|&b| char::from(b)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+    use crate::UUID;
+
+    #[test]
+    fn matches_known_short_uuids() {
+        for (uuid, short) in [
+            (
+                "3b1f8b40-222c-4a6e-b77e-779d5a94e21c",
+                "CXc85b4rqinB7s5J52TRYb",
+            ),
+            (
+                "6ba7b810-9dad-11d1-80b4-00c04fd430c8",
+                "MAnkyno2VCnFzuVMWtxBda",
+            ),
+            (
+                "00000000-0000-0000-0000-000000000000",
+                "2222222222222222222222",
+            ),
+            (
+                "ffffffff-ffff-ffff-ffff-ffffffffffff",
+                "oZEq7ovRbLq6UnGMPwc8B5",
+            ),
+        ] {
+            let uuid: UUID = uuid.parse().expect("valid UUID");
+
+            assert_eq!(uuid.to_short(), short);
+        }
+    }
+
+    #[test]
+    fn output_is_always_22_characters() {
+        for uuid in [UUID::nil(), UUID::from(1u128), UUID::gen_v4(), UUID::max()] {
+            assert_eq!(uuid.to_short().len(), 22);
+        }
+    }
+}
@@ -0,0 +1,73 @@
+use crate::{helpers, UUID};
+
+/// Writes the 32 hexadecimal digits of `uuid` using the digits in `lut`.
+const fn encode(uuid: &UUID, lut: &[u8; 16]) -> [u8; 32] {
+    let mut out = [0u8; 32];
+    let mut i = 0;
+
+    while i < 16 {
+        let b = uuid.bytes[i];
+
+        out[2 * i] = lut[(b >> 4) as usize];
+        out[2 * i + 1] = lut[(b & 0x0F) as usize];
+        i += 1;
+    }
+
+    out
+}
+
+impl UUID {
+    /// Returns the simple form as 32 lowercase hexadecimal ASCII bytes,
+    /// without allocating.
+    ///
+    /// The array holds the same characters as [`UUID::to_simple_string`],
+    /// so it can be written to a log or buffer directly, or viewed as a
+    /// `&str` through [`core::str::from_utf8`], which cannot fail on it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ps_uuid::UUID;
+    ///
+    /// assert_eq!(&UUID::NS_DNS.to_simple_ascii(), b"6ba7b8109dad11d180b400c04fd430c8");
+    /// ```
+    #[must_use]
+    pub const fn to_simple_ascii(&self) -> [u8; 32] {
+        encode(self, helpers::LUT)
+    }
+
+    /// Returns the simple form as 32 uppercase hexadecimal ASCII bytes,
+    /// without allocating; see [`UUID::to_simple_ascii`].
+    #[must_use]
+    pub const fn to_simple_ascii_upper(&self) -> [u8; 32] {
+        encode(self, helpers::LUT_UPPER)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+    use crate::UUID;
+
+    #[test]
+    fn matches_the_simple_string() {
+        let uuid = UUID::gen_v4();
+
+        assert_eq!(uuid.to_simple_ascii(), uuid.to_simple_string().as_bytes());
+        assert_eq!(
+            uuid.to_simple_ascii_upper(),
+            uuid.to_simple_string().to_uppercase().as_bytes()
+        );
+    }
+
+    #[test]
+    fn parses_back_through_from_str() {
+        for uuid in [UUID::nil(), UUID::max(), UUID::NS_DNS, UUID::gen_v4()] {
+            for ascii in [uuid.to_simple_ascii(), uuid.to_simple_ascii_upper()] {
+                let s = core::str::from_utf8(&ascii).expect("hex digits are ASCII");
+
+                assert_eq!(s.parse(), Ok(uuid));
+            }
+        }
+    }
+}
@@ -0,0 +1,57 @@
+use super::to_grouped_string::grouped;
+use crate::{helpers::LUT_UPPER, UUID};
+
+impl UUID {
+    /// Returns the hyphenated canonical form in uppercase, for legacy
+    /// systems that reject lowercase UUID strings.
+    ///
+    /// This is the uppercase counterpart of [`Display`](std::fmt::Display);
+    /// for the uppercase form without hyphens, see
+    /// [`UUID::to_simple_ascii_upper`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ps_uuid::UUID;
+    ///
+    /// assert_eq!(
+    ///     UUID::NS_DNS.to_string_uppercase(),
+    ///     "6BA7B810-9DAD-11D1-80B4-00C04FD430C8"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn to_string_uppercase(&self) -> String {
+        grouped(self, '-', LUT_UPPER)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+    use crate::UUID;
+
+    #[test]
+    fn equals_uppercased_display() {
+        for uuid in [UUID::nil(), UUID::max(), UUID::NS_URL, UUID::gen_v4()] {
+            assert_eq!(uuid.to_string_uppercase(), uuid.to_string().to_uppercase());
+        }
+    }
+
+    #[test]
+    fn parses_back() {
+        let uuid = UUID::gen_v4();
+        let parsed: UUID = uuid
+            .to_string_uppercase()
+            .parse()
+            .expect("uppercase canonical form must parse");
+
+        assert_eq!(parsed, uuid);
+    }
+
+    #[test]
+    fn has_canonical_shape() {
+        let s = UUID::max().to_string_uppercase();
+
+        assert_eq!(s, "FFFFFFFF-FFFF-FFFF-FFFF-FFFFFFFFFFFF");
+    }
+}
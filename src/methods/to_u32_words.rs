@@ -0,0 +1,40 @@
+use crate::UUID;
+
+impl UUID {
+    /// Splits the UUID into four `u32` words, the most significant first,
+    /// each in big-endian byte order.
+    ///
+    /// This is the inverse of [`UUID::from_u32_words`].
+    #[must_use]
+    pub const fn to_u32_words(&self) -> [u32; 4] {
+        let [a0, a1, a2, a3, b0, b1, b2, b3, c0, c1, c2, c3, d0, d1, d2, d3] = self.bytes;
+
+        [
+            u32::from_be_bytes([a0, a1, a2, a3]),
+            u32::from_be_bytes([b0, b1, b2, b3]),
+            u32::from_be_bytes([c0, c1, c2, c3]),
+            u32::from_be_bytes([d0, d1, d2, d3]),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::UUID;
+
+    #[test]
+    fn splits_into_big_endian_words() {
+        assert_eq!(
+            UUID::NS_DNS.to_u32_words(),
+            [0x6BA7_B810, 0x9DAD_11D1, 0x80B4_00C0, 0x4FD4_30C8]
+        );
+        assert_eq!(UUID::from(1u128).to_u32_words(), [0, 0, 0, 1]);
+    }
+
+    #[test]
+    fn roundtrip_with_from_u32_words() {
+        let uuid = UUID::gen_v4();
+
+        assert_eq!(UUID::from_u32_words(uuid.to_u32_words()), uuid);
+    }
+}
@@ -0,0 +1,45 @@
+use crate::UUID;
+
+impl UUID {
+    /// Returns the UUID as two `u64` integers, the high half first, each in
+    /// big-endian byte order.
+    ///
+    /// This suits storage schemes that split a 128-bit identifier across
+    /// two 64-bit integer columns. The inverse is [`UUID::from_u64_pair`].
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub const fn to_u64_pair(&self) -> (u64, u64) {
+        let value = self.to_u128();
+
+        ((value >> 64) as u64, value as u64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::UUID;
+
+    #[test]
+    fn splits_big_endian() {
+        let uuid = UUID::from_u128(0x0011_2233_4455_6677_8899_aabb_ccdd_eeff);
+
+        assert_eq!(
+            uuid.to_u64_pair(),
+            (0x0011_2233_4455_6677, 0x8899_aabb_ccdd_eeff)
+        );
+    }
+
+    #[test]
+    fn nil_and_max() {
+        assert_eq!(UUID::nil().to_u64_pair(), (0, 0));
+        assert_eq!(UUID::max().to_u64_pair(), (u64::MAX, u64::MAX));
+    }
+
+    #[test]
+    fn roundtrip_with_from_u64_pair() {
+        let uuid = UUID::gen_v4();
+        let (hi, lo) = uuid.to_u64_pair();
+
+        assert_eq!(UUID::from_u64_pair(hi, lo), uuid);
+    }
+}
@@ -0,0 +1,90 @@
+use crate::UUID;
+
+impl UUID {
+    /// Converts a version-1 UUID into the version-6 UUID carrying the same
+    /// timestamp, clock sequence and node, as RFC 9562 §5.6 describes.
+    ///
+    /// A version-6 UUID is returned unchanged. Returns `None` for any other
+    /// version or variant.
+    ///
+    /// # Example
+    /// ```
+    /// use ps_uuid::UUID;
+    ///
+    /// let v6 = UUID::NS_DNS.to_v6().unwrap();
+    ///
+    /// assert_eq!(v6.to_string(), "1d19dad6-ba7b-6810-80b4-00c04fd430c8");
+    /// assert_eq!(v6.get_timestamp(), UUID::NS_DNS.get_timestamp());
+    /// ```
+    #[must_use]
+    pub const fn to_v6(&self) -> Option<Self> {
+        match self.get_version() {
+            Some(1) => {
+                let [l0, l1, l2, l3, m0, m1, h0, h1, rest @ ..] = self.bytes;
+                let ticks = u64::from_be_bytes([h0 & 0x0F, h1, m0, m1, l0, l1, l2, l3]);
+
+                let (time_high, time_mid, time_low) = Self::split_ticks_v6(ticks);
+                let [a0, a1, a2, a3] = time_high.to_be_bytes();
+                let [b0, b1] = time_mid.to_be_bytes();
+                let [c0, c1] = time_low.to_be_bytes();
+                let [r0, r1, r2, r3, r4, r5, r6, r7] = rest;
+
+                Some(
+                    Self::from_bytes([
+                        a0, a1, a2, a3, b0, b1, c0, c1, r0, r1, r2, r3, r4, r5, r6, r7,
+                    ])
+                    .with_version(6),
+                )
+            }
+            Some(6) => Some(*self),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use crate::{Variant, UUID};
+
+    #[test]
+    fn matches_new_v6_for_the_same_inputs() {
+        let time = UNIX_EPOCH + Duration::from_nanos(1_700_000_000_123_456_700);
+        let node = [0xAA, 0xBB, 0xCC, 0xDD, 0xEE, 0xFF];
+
+        let v1 = UUID::new_v1(time, 0x2A3B, node).expect("valid v1 inputs");
+        let v6 = UUID::new_v6(time, 0x2A3B, node).expect("valid v6 inputs");
+
+        assert_eq!(v1.to_v6(), Some(v6));
+    }
+
+    #[test]
+    fn preserves_the_decoded_fields() {
+        let v1 = UUID::gen_v1().expect("generation must succeed");
+        let v6 = v1.to_v6().expect("v1 converts");
+
+        assert_eq!(v6.get_version(), Some(6));
+        assert_eq!(v6.get_variant(), Variant::OSF);
+        assert_eq!(v6.get_timestamp(), v1.get_timestamp());
+        assert_eq!(v6.get_clock_seq(), v1.get_clock_seq());
+        assert_eq!(v6.get_node_id(), v1.get_node_id());
+    }
+
+    #[test]
+    fn v6_is_returned_unchanged_and_others_are_rejected() {
+        let v6 = UUID::gen_v6().expect("generation must succeed");
+
+        assert_eq!(v6.to_v6(), Some(v6));
+
+        for uuid in [
+            UUID::gen_v4(),
+            UUID::nil(),
+            UUID::max(),
+            UUID::NS_DNS.with_variant(Variant::DCOM),
+        ] {
+            assert_eq!(uuid.to_v6(), None, "{uuid}");
+        }
+    }
+}
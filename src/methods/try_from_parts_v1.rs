@@ -0,0 +1,68 @@
+use crate::{UuidConstructionError, UUID};
+
+impl UUID {
+    /// Build a version-1 UUID from its wire-format fields like
+    /// [`UUID::from_parts_v1`], rejecting fields that would be truncated.
+    ///
+    /// # Errors
+    /// - `FieldOutOfRange("time_hi")` if `time_hi` exceeds 12 bits
+    ///   (`0x0FFF`), whose upper nibble would be overwritten by the version.
+    /// - `FieldOutOfRange("clock_seq")` if `clock_seq` exceeds 14 bits
+    ///   (`0x3FFF`), whose upper two bits would be overwritten by the variant.
+    pub fn try_from_parts_v1(
+        time_low: u32,
+        time_mid: u16,
+        time_hi: u16,
+        clock_seq: u16,
+        node_id: [u8; 6],
+    ) -> Result<Self, UuidConstructionError> {
+        if time_hi > 0x0FFF {
+            return Err(UuidConstructionError::FieldOutOfRange("time_hi"));
+        }
+
+        if clock_seq > 0x3FFF {
+            return Err(UuidConstructionError::FieldOutOfRange("clock_seq"));
+        }
+
+        Ok(Self::from_parts_v1(
+            time_low, time_mid, time_hi, clock_seq, node_id,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{UuidConstructionError, UUID};
+
+    const NODE: [u8; 6] = [0, 1, 2, 3, 4, 5];
+
+    #[test]
+    fn in_range_fields_match_from_parts_v1() {
+        assert_eq!(
+            UUID::try_from_parts_v1(0x0123_4567, 0x89AB, 0x0FFF, 0x3FFF, NODE),
+            Ok(UUID::from_parts_v1(
+                0x0123_4567,
+                0x89AB,
+                0x0FFF,
+                0x3FFF,
+                NODE
+            ))
+        );
+    }
+
+    #[test]
+    fn wide_time_hi_is_rejected() {
+        assert_eq!(
+            UUID::try_from_parts_v1(0, 0, 0x1000, 0, NODE),
+            Err(UuidConstructionError::FieldOutOfRange("time_hi"))
+        );
+    }
+
+    #[test]
+    fn wide_clock_seq_is_rejected() {
+        assert_eq!(
+            UUID::try_from_parts_v1(0, 0, 0, 0x4000, NODE),
+            Err(UuidConstructionError::FieldOutOfRange("clock_seq"))
+        );
+    }
+}
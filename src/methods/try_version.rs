@@ -0,0 +1,58 @@
+use crate::{UuidConstructionError, UUID};
+
+impl UUID {
+    /// Returns the version number, or an error if the UUID is not of the OSF
+    /// variant.
+    ///
+    /// This is [`UUID::get_version`] for code that already returns a
+    /// [`Result`] and wants to propagate the failure with `?`.
+    ///
+    /// # Errors
+    /// - `NotRfc4122` is returned if the UUID is not of the OSF variant.
+    pub const fn try_version(&self) -> Result<u8, UuidConstructionError> {
+        match self.get_version() {
+            Some(version) => Ok(version),
+            None => Err(UuidConstructionError::NotRfc4122),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{UuidConstructionError, Variant, UUID};
+
+    #[test]
+    fn osf_uuids_yield_their_version() {
+        assert_eq!(UUID::gen_v4().try_version(), Ok(4));
+        assert_eq!(UUID::NS_DNS.try_version(), Ok(1));
+    }
+
+    #[test]
+    fn other_variants_are_rejected() {
+        for uuid in [
+            UUID::NS_DNS.with_variant(Variant::DCOM),
+            UUID::NS_DNS.with_variant(Variant::NCS),
+            UUID::nil(),
+            UUID::max(),
+        ] {
+            assert_eq!(
+                uuid.try_version(),
+                Err(UuidConstructionError::NotRfc4122),
+                "{uuid}"
+            );
+        }
+    }
+
+    #[test]
+    fn composes_with_the_question_mark_operator() {
+        fn is_time_based(uuid: UUID) -> Result<bool, UuidConstructionError> {
+            Ok(matches!(uuid.try_version()?, 1 | 2 | 6 | 7))
+        }
+
+        assert_eq!(is_time_based(UUID::NS_DNS), Ok(true));
+        assert_eq!(
+            is_time_based(UUID::NS_DNS.with_variant(Variant::DCOM)),
+            Err(UuidConstructionError::NotRfc4122)
+        );
+    }
+}
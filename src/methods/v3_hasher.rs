@@ -0,0 +1,39 @@
+use crate::{Md5, UUID};
+
+impl UUID {
+    /// Returns an MD5 hasher already fed with the bytes of `namespace`.
+    ///
+    /// Feeding a name to (a clone of) the returned hasher and passing its
+    /// digest to [`UUID::from_parts_v3`] yields the same UUID as
+    /// [`UUID::new_v3`], so the namespace prefix is hashed once however many
+    /// names are derived under it.
+    #[must_use]
+    pub fn v3_hasher(namespace: &Self) -> Md5 {
+        let mut hasher = Md5::new();
+
+        hasher.update(namespace.as_bytes());
+
+        hasher
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::UUID;
+
+    #[test]
+    fn prepared_hasher_matches_new_v3() {
+        let prepared = UUID::v3_hasher(&UUID::NS_URL);
+
+        for name in ["", "https://example.com/", "a longer name spanning blocks"] {
+            let mut hasher = prepared.clone();
+
+            hasher.update(name.as_bytes());
+
+            assert_eq!(
+                UUID::from_parts_v3(hasher.finalize()),
+                UUID::new_v3(&UUID::NS_URL, name)
+            );
+        }
+    }
+}
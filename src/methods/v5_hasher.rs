@@ -0,0 +1,39 @@
+use crate::{Sha1, UUID};
+
+impl UUID {
+    /// Returns a SHA-1 hasher already fed with the bytes of `namespace`.
+    ///
+    /// Feeding a name to (a clone of) the returned hasher and passing its
+    /// digest to [`UUID::from_parts_v5`] yields the same UUID as
+    /// [`UUID::new_v5`], so the namespace prefix is hashed once however many
+    /// names are derived under it.
+    #[must_use]
+    pub fn v5_hasher(namespace: &Self) -> Sha1 {
+        let mut hasher = Sha1::new();
+
+        hasher.update(namespace.as_bytes());
+
+        hasher
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::UUID;
+
+    #[test]
+    fn prepared_hasher_matches_new_v5() {
+        let prepared = UUID::v5_hasher(&UUID::NS_DNS);
+
+        for name in ["", "example.com", "a longer name spanning blocks"] {
+            let mut hasher = prepared.clone();
+
+            hasher.update(name.as_bytes());
+
+            assert_eq!(
+                UUID::from_parts_v5(hasher.finalize()),
+                UUID::new_v5(&UUID::NS_DNS, name)
+            );
+        }
+    }
+}
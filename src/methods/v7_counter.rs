@@ -0,0 +1,69 @@
+use crate::UUID;
+
+impl UUID {
+    /// Returns the 12-bit `rand_a` field of a version-7 UUID, or `None` if
+    /// the UUID is not version 7.
+    ///
+    /// Within one millisecond, [`UUID::gen_v7_monotonic`] fills this field
+    /// with a strictly increasing sub-millisecond value, and
+    /// [`UUID::gen_v7_mode`] with [`V7Mode::Counter`](crate::V7Mode::Counter)
+    /// with a per-millisecond counter. For such UUIDs, ordering by
+    /// [`UUID::v7_unix_millis`] and then by this value reconstructs creation
+    /// order even where the timestamps tie.
+    ///
+    /// The field carries no ordering information for UUIDs from other
+    /// generators: RFC 9562 allows it to hold plain random bits, as it does
+    /// for [`V7Mode::Random`](crate::V7Mode::Random), and nothing in the UUID
+    /// records which scheme filled it.
+    #[must_use]
+    pub const fn v7_counter(&self) -> Option<u16> {
+        if !self.is_v7() {
+            return None;
+        }
+
+        Some(u16::from_be_bytes([self.bytes[6], self.bytes[7]]) & 0x0FFF)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+    use crate::UUID;
+
+    #[test]
+    fn reads_rand_a() {
+        let uuid = UUID::from_parts_v7(1_000, 0x0ABC, u64::MAX);
+
+        assert_eq!(uuid.v7_counter(), Some(0x0ABC));
+        assert_eq!(UUID::from_parts_v7(1_000, 0, 0).v7_counter(), Some(0));
+    }
+
+    #[test]
+    fn non_v7_uuids_yield_none() {
+        assert_eq!(UUID::gen_v4().v7_counter(), None);
+        assert_eq!(UUID::NS_DNS.v7_counter(), None);
+        assert_eq!(UUID::max().v7_counter(), None);
+    }
+
+    #[test]
+    fn reconstructs_monotonic_order_within_a_millisecond() {
+        let ids: Vec<UUID> = (0..1000)
+            .map(|_| UUID::gen_v7_monotonic().expect("generation must succeed"))
+            .collect();
+
+        let mut tied = 0;
+
+        for pair in ids.windows(2) {
+            let earlier = (pair[0].v7_unix_millis(), pair[0].v7_counter());
+            let later = (pair[1].v7_unix_millis(), pair[1].v7_counter());
+
+            if earlier.0 == later.0 {
+                tied += 1;
+            }
+
+            assert!(earlier < later, "{} !< {}", pair[0], pair[1]);
+        }
+
+        assert!(tied > 0, "at least two UUIDs must share a millisecond");
+    }
+}
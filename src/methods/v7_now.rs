@@ -0,0 +1,61 @@
+use std::time::Duration;
+
+use rand::random;
+
+use crate::{UuidConstructionError, UUID};
+
+impl UUID {
+    /// Generate an RFC-4122 **Version 7** UUID without surfacing an error.
+    ///
+    /// This delegates to [`UUID::gen_v7`], which only fails under a
+    /// pathological clock. In that case the timestamp is clamped to the
+    /// nearest representable instant instead:
+    ///
+    /// - `TimestampBeforeEpoch` clamps to 1970-01-01 00:00:00 UTC,
+    /// - `TimestampOverflow` clamps to the last millisecond the 48-bit
+    ///   field can hold (≈ 10889-08-02 05:31:50.655 UTC).
+    ///
+    /// A clamped UUID still carries 64 fresh random bits, but it is no longer
+    /// ordered relative to UUIDs generated while the clock was sane.
+    #[must_use]
+    pub fn v7_now() -> Self {
+        Self::gen_v7().unwrap_or_else(|err| {
+            let timestamp = match err {
+                UuidConstructionError::TimestampBeforeEpoch => Duration::ZERO,
+                // `gen_v7` reports no error but the two timestamp ones; the
+                // rest are listed so a new variant must be placed here.
+                UuidConstructionError::TimestampOverflow
+                | UuidConstructionError::IntegerConversion(_)
+                | UuidConstructionError::RateExceeded
+                | UuidConstructionError::UnsupportedVersion(_)
+                | UuidConstructionError::IrrelevantField(_)
+                | UuidConstructionError::FieldOutOfRange(_)
+                | UuidConstructionError::VersionMismatch { .. }
+                | UuidConstructionError::NotRfc4122 => Duration::from_millis((1 << 48) - 1),
+            };
+
+            Self::new_v7(timestamp, random())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Variant, UUID};
+
+    #[test]
+    fn v7_now_produces_valid_uuid() {
+        let uuid = UUID::v7_now();
+
+        assert_eq!(uuid.get_version(), Some(7));
+        assert_eq!(uuid.get_variant(), Variant::OSF);
+    }
+
+    #[test]
+    fn v7_now_is_unique() {
+        let a = UUID::v7_now();
+        let b = UUID::v7_now();
+
+        assert_ne!(a, b);
+    }
+}
@@ -0,0 +1,63 @@
+use crate::UUID;
+
+impl UUID {
+    /// Returns the 48-bit Unix millisecond timestamp of a version-7 UUID, or
+    /// `None` if the UUID is not version 7.
+    ///
+    /// This reads bytes 0‥=5 big-endian without constructing a `SystemTime`,
+    /// and agrees with [`UUID::get_timestamp`], which also floors version-7
+    /// timestamps to the millisecond.
+    #[must_use]
+    pub const fn v7_unix_millis(&self) -> Option<u64> {
+        if !self.is_v7() {
+            return None;
+        }
+
+        let [b0, b1, b2, b3, b4, b5, ..] = self.bytes;
+
+        Some(u64::from_be_bytes([0, 0, b0, b1, b2, b3, b4, b5]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use crate::UUID;
+
+    #[test]
+    fn reads_the_encoded_milliseconds() {
+        let ms = 0x0123_4567_89AB;
+        let uuid = UUID::from_parts_v7(ms, 0x0FFF, u64::MAX);
+
+        assert_eq!(uuid.v7_unix_millis(), Some(ms));
+        assert_eq!(
+            uuid.get_timestamp(),
+            Some(UNIX_EPOCH + Duration::from_millis(ms))
+        );
+    }
+
+    #[test]
+    fn covers_the_full_48_bit_range() {
+        assert_eq!(UUID::from_parts_v7(0, 0, 0).v7_unix_millis(), Some(0));
+        assert_eq!(
+            UUID::from_parts_v7(u64::MAX, 0, 0).v7_unix_millis(),
+            Some((1 << 48) - 1)
+        );
+    }
+
+    #[test]
+    fn other_versions_yield_none() {
+        assert_eq!(UUID::gen_v4().v7_unix_millis(), None);
+        assert_eq!(UUID::nil().v7_unix_millis(), None);
+        assert_eq!(UUID::max().v7_unix_millis(), None);
+    }
+
+    #[test]
+    fn const_context() {
+        const MS: Option<u64> =
+            UUID::from_u128(0x0000_0000_0001_7000_8000_0000_0000_0000).v7_unix_millis();
+
+        assert_eq!(MS, Some(1));
+    }
+}
@@ -0,0 +1,57 @@
+use crate::UUID;
+
+impl UUID {
+    /// Returns `true` if `self` is the version-3 UUID of `name` within
+    /// `namespace`, i.e. equal to [`UUID::new_v3`] of the same inputs.
+    ///
+    /// This confirms a stored name-to-UUID mapping without keeping the
+    /// MD5 digest around; any UUID of another version yields `false`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ps_uuid::UUID;
+    ///
+    /// let uuid: UUID = "6fa459ea-ee8a-3ca4-894e-db77e160355e".parse().unwrap();
+    ///
+    /// assert!(uuid.verify_v3(&UUID::NS_DNS, "python.org"));
+    /// ```
+    #[must_use]
+    pub fn verify_v3<N>(&self, namespace: &Self, name: N) -> bool
+    where
+        N: AsRef<[u8]>,
+    {
+        *self == Self::new_v3(namespace, name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+    use crate::UUID;
+
+    const PYTHON_ORG: &str = "6fa459ea-ee8a-3ca4-894e-db77e160355e";
+
+    #[test]
+    fn rfc_example_verifies() {
+        let uuid: UUID = PYTHON_ORG.parse().expect("valid UUID");
+
+        assert!(uuid.verify_v3(&UUID::NS_DNS, "python.org"));
+        assert!(uuid.verify_v3(&UUID::NS_DNS, b"python.org"));
+    }
+
+    #[test]
+    fn different_name_or_namespace_fails() {
+        let uuid: UUID = PYTHON_ORG.parse().expect("valid UUID");
+
+        assert!(!uuid.verify_v3(&UUID::NS_DNS, "python.com"));
+        assert!(!uuid.verify_v3(&UUID::NS_URL, "python.org"));
+    }
+
+    #[test]
+    fn other_versions_fail() {
+        let other = UUID::new_v5(&UUID::NS_DNS, "python.org");
+
+        assert!(!other.verify_v3(&UUID::NS_DNS, "python.org"));
+    }
+}
@@ -0,0 +1,57 @@
+use crate::UUID;
+
+impl UUID {
+    /// Returns `true` if `self` is the version-5 UUID of `name` within
+    /// `namespace`, i.e. equal to [`UUID::new_v5`] of the same inputs.
+    ///
+    /// This confirms a stored name-to-UUID mapping without keeping the
+    /// SHA-1 digest around; any UUID of another version yields `false`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ps_uuid::UUID;
+    ///
+    /// let uuid: UUID = "886313e1-3b8a-5372-9b90-0c9aee199e5d".parse().unwrap();
+    ///
+    /// assert!(uuid.verify_v5(&UUID::NS_DNS, "python.org"));
+    /// ```
+    #[must_use]
+    pub fn verify_v5<N>(&self, namespace: &Self, name: N) -> bool
+    where
+        N: AsRef<[u8]>,
+    {
+        *self == Self::new_v5(namespace, name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+    use crate::UUID;
+
+    const PYTHON_ORG: &str = "886313e1-3b8a-5372-9b90-0c9aee199e5d";
+
+    #[test]
+    fn rfc_example_verifies() {
+        let uuid: UUID = PYTHON_ORG.parse().expect("valid UUID");
+
+        assert!(uuid.verify_v5(&UUID::NS_DNS, "python.org"));
+        assert!(uuid.verify_v5(&UUID::NS_DNS, b"python.org"));
+    }
+
+    #[test]
+    fn different_name_or_namespace_fails() {
+        let uuid: UUID = PYTHON_ORG.parse().expect("valid UUID");
+
+        assert!(!uuid.verify_v5(&UUID::NS_DNS, "python.com"));
+        assert!(!uuid.verify_v5(&UUID::NS_URL, "python.org"));
+    }
+
+    #[test]
+    fn other_versions_fail() {
+        let other = UUID::new_v3(&UUID::NS_DNS, "python.org");
+
+        assert!(!other.verify_v5(&UUID::NS_DNS, "python.org"));
+    }
+}
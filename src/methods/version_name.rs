@@ -0,0 +1,103 @@
+use crate::UUID;
+
+impl UUID {
+    /// Returns a short human-readable name for the UUID's version, for use
+    /// in logs and CLI output.
+    ///
+    /// | UUID                 | Name                  |
+    /// |----------------------|-----------------------|
+    /// | version 1            | `"time (v1)"`         |
+    /// | version 2            | `"dce-security (v2)"` |
+    /// | version 3            | `"name-md5 (v3)"`     |
+    /// | version 4            | `"random (v4)"`       |
+    /// | version 5            | `"name-sha1 (v5)"`    |
+    /// | version 6            | `"time-ordered (v6)"` |
+    /// | version 7            | `"unix-time (v7)"`    |
+    /// | version 8            | `"custom (v8)"`       |
+    /// | [`UUID::nil`]        | `"nil"`               |
+    /// | [`UUID::max`]        | `"max"`               |
+    /// | anything else        | `"unknown"`           |
+    ///
+    /// Versions are only recognized on UUIDs of the OSF variant, as with
+    /// [`UUID::get_version`].
+    #[must_use]
+    pub const fn version_name(&self) -> &'static str {
+        if self.is_nil() {
+            return "nil";
+        }
+
+        if self.is_max() {
+            return "max";
+        }
+
+        match self.get_version() {
+            Some(1) => "time (v1)",
+            Some(2) => "dce-security (v2)",
+            Some(3) => "name-md5 (v3)",
+            Some(4) => "random (v4)",
+            Some(5) => "name-sha1 (v5)",
+            Some(6) => "time-ordered (v6)",
+            Some(7) => "unix-time (v7)",
+            Some(8) => "custom (v8)",
+            _ => "unknown",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Variant, UUID};
+
+    #[test]
+    fn every_version_is_named() {
+        let names = [
+            (1, "time (v1)"),
+            (2, "dce-security (v2)"),
+            (3, "name-md5 (v3)"),
+            (4, "random (v4)"),
+            (5, "name-sha1 (v5)"),
+            (6, "time-ordered (v6)"),
+            (7, "unix-time (v7)"),
+            (8, "custom (v8)"),
+        ];
+
+        for (version, name) in names {
+            let uuid = UUID::gen_v4().with_version(version);
+
+            assert_eq!(uuid.version_name(), name, "v{version}");
+        }
+    }
+
+    #[test]
+    fn nil_and_max_are_named() {
+        assert_eq!(UUID::nil().version_name(), "nil");
+        assert_eq!(UUID::max().version_name(), "max");
+    }
+
+    #[test]
+    fn unassigned_versions_are_unknown() {
+        for version in [0, 9, 10, 11, 12, 13, 14, 15] {
+            let uuid = UUID::gen_v4().with_version(version);
+
+            assert_eq!(uuid.version_name(), "unknown", "v{version}");
+        }
+    }
+
+    #[test]
+    fn other_variants_are_unknown() {
+        for variant in [Variant::NCS, Variant::DCOM, Variant::Reserved] {
+            let uuid = UUID::gen_v4().with_variant(variant);
+
+            assert_eq!(uuid.version_name(), "unknown", "{variant}");
+        }
+    }
+
+    #[test]
+    fn generated_uuids_are_named() {
+        assert_eq!(UUID::NS_DNS.version_name(), "time (v1)");
+        assert_eq!(
+            UUID::new_v5(&UUID::NS_DNS, "a").version_name(),
+            "name-sha1 (v5)"
+        );
+    }
+}
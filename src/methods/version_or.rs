@@ -0,0 +1,33 @@
+use crate::UUID;
+
+impl UUID {
+    /// Returns the version number, or `default` if the UUID is not of the
+    /// OSF variant.
+    ///
+    /// Unlike [`UUID::get_version`], the result is a plain `u8`, which makes
+    /// a convenient key when tallying UUIDs by version in a `HashMap`.
+    #[must_use]
+    pub const fn version_or(&self, default: u8) -> u8 {
+        match self.get_version() {
+            Some(version) => version,
+            None => default,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::UUID;
+
+    #[test]
+    fn osf_uuids_report_their_version() {
+        assert_eq!(UUID::gen_v4().version_or(0xFF), 4);
+        assert_eq!(UUID::NS_DNS.version_or(0xFF), 1);
+    }
+
+    #[test]
+    fn other_variants_report_the_default() {
+        assert_eq!(UUID::nil().version_or(0xFF), 0xFF);
+        assert_eq!(UUID::max().version_or(0xFF), 0xFF);
+    }
+}
@@ -0,0 +1,98 @@
+//! The Windows `GUID` layout for FFI.
+
+use crate::UUID;
+
+/// A UUID laid out as the Windows `GUID` structure.
+///
+/// `Data1`, `Data2` and `Data3` are native integers, so on the
+/// little-endian targets Windows runs on, the first three fields are stored
+/// byte-swapped relative to [`UUID`]'s big-endian bytes, while `Data4` is
+/// stored as-is. The conversions move the field values, not the bytes, so
+/// a `GUID` received over FFI converts to the UUID its registry-format
+/// string (`{…}`) names. See [`PosixUuid`](crate::PosixUuid) for the
+/// POSIX `uuid_t`.
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(C)]
+pub struct WindowsGuid {
+    /// The first 8 hex digits.
+    pub data1: u32,
+    /// The next 4 hex digits.
+    pub data2: u16,
+    /// The next 4 hex digits, including the version.
+    pub data3: u16,
+    /// The last 16 hex digits, including the variant, in order.
+    pub data4: [u8; 8],
+}
+
+impl From<UUID> for WindowsGuid {
+    fn from(uuid: UUID) -> Self {
+        let [a0, a1, a2, a3, b0, b1, c0, c1, d0, d1, d2, d3, d4, d5, d6, d7] = uuid.bytes;
+
+        Self {
+            data1: u32::from_be_bytes([a0, a1, a2, a3]),
+            data2: u16::from_be_bytes([b0, b1]),
+            data3: u16::from_be_bytes([c0, c1]),
+            data4: [d0, d1, d2, d3, d4, d5, d6, d7],
+        }
+    }
+}
+
+impl From<WindowsGuid> for UUID {
+    fn from(guid: WindowsGuid) -> Self {
+        let [a0, a1, a2, a3] = guid.data1.to_be_bytes();
+        let [b0, b1] = guid.data2.to_be_bytes();
+        let [c0, c1] = guid.data3.to_be_bytes();
+        let [d0, d1, d2, d3, d4, d5, d6, d7] = guid.data4;
+
+        Self::from_bytes([
+            a0, a1, a2, a3, b0, b1, c0, c1, d0, d1, d2, d3, d4, d5, d6, d7,
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::mem::{align_of, size_of};
+
+    use super::WindowsGuid;
+    use crate::UUID;
+
+    #[test]
+    fn fields_hold_the_hex_groups() {
+        let guid = WindowsGuid::from(UUID::NS_DNS);
+
+        assert_eq!(guid.data1, 0x6BA7_B810);
+        assert_eq!(guid.data2, 0x9DAD);
+        assert_eq!(guid.data3, 0x11D1);
+        assert_eq!(guid.data4, [0x80, 0xB4, 0x00, 0xC0, 0x4F, 0xD4, 0x30, 0xC8]);
+        assert_eq!(UUID::from(guid), UUID::NS_DNS);
+    }
+
+    #[cfg(target_endian = "little")]
+    #[test]
+    fn memory_swaps_the_first_three_fields() {
+        let guid = WindowsGuid::from(UUID::NS_DNS);
+
+        // SAFETY: `WindowsGuid` is `#[repr(C)]` with no padding (4 + 2 + 2
+        // + 8 bytes), so all 16 bytes are initialized.
+        let bytes: [u8; 16] = unsafe { core::mem::transmute(guid) };
+
+        assert_eq!(
+            bytes,
+            *UUID::parse_braced_guid("{6ba7b810-9dad-11d1-80b4-00c04fd430c8}")
+                .expect("valid braced GUID")
+                .as_bytes()
+        );
+        assert_eq!(
+            &bytes[..8],
+            &[0x10, 0xB8, 0xA7, 0x6B, 0xAD, 0x9D, 0xD1, 0x11]
+        );
+        assert_eq!(&bytes[8..], &UUID::NS_DNS.as_bytes()[8..]);
+    }
+
+    #[test]
+    fn matches_the_guid_layout() {
+        assert_eq!(size_of::<WindowsGuid>(), 16);
+        assert_eq!(align_of::<WindowsGuid>(), 4);
+    }
+}
@@ -0,0 +1,74 @@
+use crate::UUID;
+
+impl UUID {
+    /// Returns a copy with bytes 10‥16 replaced by `node`.
+    ///
+    /// This is the setter counterpart of [`UUID::get_node_id`]: the version,
+    /// variant, timestamp and clock sequence are left intact, so a decoded
+    /// v1, v2 or v6 UUID can be re-issued under a different node. The bytes
+    /// are written whatever the version, even one that does not carry a node
+    /// identifier.
+    #[must_use]
+    pub const fn with_node_id(self, node: [u8; 6]) -> Self {
+        let mut uuid = self;
+        let [n0, n1, n2, n3, n4, n5] = node;
+
+        uuid.bytes[10] = n0;
+        uuid.bytes[11] = n1;
+        uuid.bytes[12] = n2;
+        uuid.bytes[13] = n3;
+        uuid.bytes[14] = n4;
+        uuid.bytes[15] = n5;
+
+        uuid
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+    use crate::UUID;
+
+    #[test]
+    fn changes_only_the_last_six_bytes() {
+        let node = [0xAA, 0xBB, 0xCC, 0xDD, 0xEE, 0xFF];
+        let uuid = UUID::NS_DNS.with_node_id(node);
+
+        assert_eq!(uuid.as_bytes()[..10], UUID::NS_DNS.as_bytes()[..10]);
+        assert_eq!(uuid.as_bytes()[10..], node);
+    }
+
+    #[test]
+    fn round_trips_through_get_node_id() {
+        let node = [1, 2, 3, 4, 5, 6];
+        let uuid = UUID::gen_v1()
+            .expect("generation must succeed")
+            .with_node_id(node);
+
+        assert_eq!(uuid.get_version(), Some(1));
+        assert_eq!(
+            uuid.get_node_id().expect("v1 carries a node ID").as_bytes(),
+            &node
+        );
+    }
+
+    #[test]
+    fn preserves_the_timestamp() {
+        let uuid = UUID::gen_v6().expect("generation must succeed");
+
+        assert_eq!(
+            uuid.with_node_id([0; 6]).get_timestamp(),
+            uuid.get_timestamp()
+        );
+    }
+
+    #[test]
+    fn const_context() {
+        const UUID_CONST: UUID = UUID::nil().with_node_id([0xFF; 6]);
+
+        assert_eq!(
+            UUID_CONST.to_string(),
+            "00000000-0000-0000-0000-ffffffffffff"
+        );
+    }
+}
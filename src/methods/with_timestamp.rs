@@ -0,0 +1,163 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::{UuidConstructionError, UUID};
+
+impl UUID {
+    /// Returns a copy of this time-based UUID with its timestamp fields
+    /// re-encoded from `time`, keeping every other field intact.
+    ///
+    /// - Versions 1 and 6 rewrite all 60 timestamp bits (bytes 0..8).
+    /// - Version 2 rewrites only `time_mid` and `time_hi` (bytes 4..8), since
+    ///   `time_low` holds the local ID.
+    /// - Version 7 rewrites the 48-bit millisecond field and the 12-bit
+    ///   sub-millisecond fraction in `rand_a`, exactly as [`UUID::new_v7`]
+    ///   encodes them.
+    ///
+    /// The clock sequence, node, DCE domain, and `rand_b` are preserved, and
+    /// the version and variant bits are re-applied.
+    ///
+    /// # Errors
+    /// - `NotRfc4122` if `self` is not of the OSF variant.
+    /// - `UnsupportedVersion` if `self` is an RFC 4122 UUID of a version
+    ///   other than 1, 2, 6, or 7; the payload is the version nibble.
+    /// - `TimestampBeforeEpoch` if `time` predates the version's epoch
+    ///   (1582-10-15 for versions 1, 2, and 6; 1970-01-01 for version 7).
+    /// - `TimestampOverflow` if `time` does not fit the version's timestamp
+    ///   field.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::time::{Duration, UNIX_EPOCH};
+    ///
+    /// use ps_uuid::UUID;
+    ///
+    /// let t = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+    /// let uuid = UUID::gen_v6().unwrap().with_timestamp(t).unwrap();
+    ///
+    /// assert_eq!(uuid.get_timestamp(), Some(t));
+    /// ```
+    pub fn with_timestamp(self, time: SystemTime) -> Result<Self, UuidConstructionError> {
+        let version @ (1 | 2 | 6 | 7) = self.try_version()? else {
+            return Err(UuidConstructionError::UnsupportedVersion(
+                self.bytes[6] >> 4,
+            ));
+        };
+
+        let (template, range) = match version {
+            1 => (Self::new_v1(time, 0, [0; 6])?, 0..8),
+            2 => (Self::new_v1(time, 0, [0; 6])?, 4..8),
+            6 => (Self::new_v6(time, 0, [0; 6])?, 0..8),
+            _ => {
+                let since_unix = time
+                    .duration_since(UNIX_EPOCH)
+                    .map_err(|_| UuidConstructionError::TimestampBeforeEpoch)?;
+
+                (Self::new_v7_checked(since_unix, [0; 8])?, 0..8)
+            }
+        };
+
+        let mut uuid = self;
+
+        uuid.bytes[range.clone()].copy_from_slice(&template.bytes[range]);
+
+        Ok(uuid.with_version(version))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used)]
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    use crate::{UuidConstructionError, Variant, UUID};
+
+    const NODE: [u8; 6] = [0xAA, 0xBB, 0xCC, 0xDD, 0xEE, 0xFF];
+
+    fn t() -> SystemTime {
+        UNIX_EPOCH + Duration::from_nanos(1_700_000_000_123_456_700)
+    }
+
+    #[test]
+    fn v6_changes_only_the_timestamp_bytes() {
+        let original = UUID::new_v6(UNIX_EPOCH, 0x1234, NODE).expect("valid v6");
+        let rebased = original.with_timestamp(t()).expect("v6 is time-based");
+
+        assert_eq!(rebased.as_bytes()[8..], original.as_bytes()[8..]);
+        assert_ne!(rebased.as_bytes()[..8], original.as_bytes()[..8]);
+        assert_eq!(rebased.get_version(), Some(6));
+        assert_eq!(rebased.get_timestamp(), Some(t()));
+        assert_eq!(rebased, UUID::new_v6(t(), 0x1234, NODE).expect("valid v6"));
+    }
+
+    #[test]
+    fn v1_matches_new_v1() {
+        let original = UUID::new_v1(UNIX_EPOCH, 0x1234, NODE).expect("valid v1");
+
+        assert_eq!(
+            original.with_timestamp(t()),
+            UUID::new_v1(t(), 0x1234, NODE)
+        );
+    }
+
+    #[test]
+    fn v2_keeps_the_local_id() {
+        let original = UUID::new_v2(1, 0xDEAD_BEEF, UNIX_EPOCH, 0x1234, NODE).expect("valid v2");
+
+        assert_eq!(
+            original.with_timestamp(t()),
+            UUID::new_v2(1, 0xDEAD_BEEF, t(), 0x1234, NODE)
+        );
+    }
+
+    #[test]
+    fn v7_keeps_rand_b() {
+        let since_unix = t().duration_since(UNIX_EPOCH).expect("after the epoch");
+        let original = UUID::new_v7(Duration::ZERO, [0x5A; 8]);
+
+        assert_eq!(
+            original.with_timestamp(t()),
+            Ok(UUID::new_v7(since_unix, [0x5A; 8]))
+        );
+    }
+
+    #[test]
+    fn non_time_versions_are_rejected() {
+        assert_eq!(
+            UUID::gen_v4().with_timestamp(t()),
+            Err(UuidConstructionError::UnsupportedVersion(4))
+        );
+        assert_eq!(
+            UUID::new_v5(&UUID::NS_DNS, "python.org").with_timestamp(t()),
+            Err(UuidConstructionError::UnsupportedVersion(5))
+        );
+    }
+
+    #[test]
+    fn other_variants_are_rejected() {
+        for uuid in [
+            UUID::nil(),
+            UUID::max(),
+            UUID::NS_DNS.with_variant(Variant::DCOM),
+        ] {
+            assert_eq!(
+                uuid.with_timestamp(t()),
+                Err(UuidConstructionError::NotRfc4122)
+            );
+        }
+    }
+
+    #[test]
+    fn out_of_range_times_are_rejected() {
+        let v7 = UUID::new_v7(Duration::ZERO, [0; 8]);
+
+        assert_eq!(
+            v7.with_timestamp(UNIX_EPOCH - Duration::from_secs(1)),
+            Err(UuidConstructionError::TimestampBeforeEpoch)
+        );
+        assert_eq!(
+            v7.with_timestamp(UNIX_EPOCH + Duration::from_millis(1 << 48)),
+            Err(UuidConstructionError::TimestampOverflow)
+        );
+    }
+}
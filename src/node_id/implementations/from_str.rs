@@ -0,0 +1,104 @@
+use std::str::FromStr;
+
+use crate::{NodeId, NodeIdParseError, NODE_ID_BYTES};
+
+impl FromStr for NodeId {
+    type Err = NodeIdParseError;
+
+    /// Accepts a MAC address in any of its common spellings, in either case:
+    ///   - colon-separated   `aa:bb:cc:dd:ee:ff`
+    ///   - hyphen-separated  `aa-bb-cc-dd-ee-ff`
+    ///   - 12 bare hex digits `aabbccddeeff`
+    ///
+    /// A separated form must use the same separator throughout.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let separator = match s.len() {
+            12 => None,
+            17 => Some(s.as_bytes()[2]),
+            _ => return Err(NodeIdParseError::InvalidLength),
+        };
+
+        let mut bytes = [0u8; NODE_ID_BYTES];
+        let mut nibbles = 0;
+
+        for (idx, ch) in s.char_indices() {
+            if let Some(separator) = separator.filter(|_| idx % 3 == 2) {
+                if !matches!(separator, b':' | b'-') || ch != char::from(separator) {
+                    return Err(NodeIdParseError::InvalidCharacter { ch, idx });
+                }
+
+                continue;
+            }
+
+            let Some(digit) = ch.to_digit(16) else {
+                return Err(NodeIdParseError::InvalidCharacter { ch, idx });
+            };
+
+            // A hex digit is below 16, so the cast cannot truncate.
+            #[allow(clippy::cast_possible_truncation)]
+            let digit = digit as u8;
+
+            bytes[nibbles / 2] = bytes[nibbles / 2] << 4 | digit;
+            nibbles += 1;
+        }
+
+        Ok(Self { bytes })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{NodeId, NodeIdParseError};
+
+    const NODE: NodeId = NodeId {
+        bytes: [0xAA, 0xBB, 0xCC, 0x0D, 0xEE, 0xFF],
+    };
+
+    #[test]
+    fn accepts_common_spellings() {
+        for s in [
+            "aa:bb:cc:0d:ee:ff",
+            "AA:BB:CC:0D:EE:FF",
+            "aa-bb-cc-0d-ee-ff",
+            "aabbcc0deeff",
+            "AaBbCc0DeEfF",
+        ] {
+            assert_eq!(s.parse(), Ok(NODE), "{s}");
+        }
+    }
+
+    #[test]
+    fn rejects_wrong_length() {
+        for s in ["", "aa:bb:cc:dd:ee", "aabbccddeeff00", "aa:bb:cc:dd:ee:ff:"] {
+            assert_eq!(
+                s.parse::<NodeId>(),
+                Err(NodeIdParseError::InvalidLength),
+                "{s}"
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_bad_digits_and_separators() {
+        assert_eq!(
+            "aa:bb:cc:dd:ee:fg".parse::<NodeId>(),
+            Err(NodeIdParseError::InvalidCharacter { ch: 'g', idx: 16 })
+        );
+        assert_eq!(
+            "aa:bb-cc:dd:ee:ff".parse::<NodeId>(),
+            Err(NodeIdParseError::InvalidCharacter { ch: '-', idx: 5 })
+        );
+        assert_eq!(
+            "aa.bb.cc.dd.ee.ff".parse::<NodeId>(),
+            Err(NodeIdParseError::InvalidCharacter { ch: '.', idx: 2 })
+        );
+        assert_eq!(
+            "aab:bcc:ddeeff".parse::<NodeId>(),
+            Err(NodeIdParseError::InvalidLength)
+        );
+        assert_eq!(
+            "aa:bb:cc:dd:ee:+f".parse::<NodeId>(),
+            Err(NodeIdParseError::InvalidCharacter { ch: '+', idx: 15 })
+        );
+    }
+}
@@ -3,3 +3,4 @@ mod as_ref;
 mod deref;
 mod deref_mut;
 mod from;
+mod from_str;
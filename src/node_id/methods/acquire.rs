@@ -0,0 +1,43 @@
+use std::sync::OnceLock;
+
+use crate::NodeId;
+
+/// The node ID chosen by the first call to [`NodeId::acquire`].
+static ACQUIRED: OnceLock<NodeId> = OnceLock::new();
+
+impl NodeId {
+    /// Returns this machine's MAC address as a node ID, or a random one with
+    /// the multicast bit set if no MAC address is found.
+    ///
+    /// The result is chosen once per process and cached, so repeated calls,
+    /// such as one per [`State::default`], do not enumerate the network
+    /// interfaces again. See [`NodeId::from_mac_address`] and
+    /// [`NodeId::random`].
+    ///
+    /// [`State::default`]: crate::State::default
+    #[must_use]
+    pub fn acquire() -> Self {
+        *ACQUIRED.get_or_init(|| Self::from_mac_address().unwrap_or_else(Self::random))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{NodeId, NODE_ID_BYTES};
+
+    #[test]
+    fn repeated_calls_return_the_cached_node() {
+        let first = NodeId::acquire();
+
+        assert_eq!(first.bytes.len(), NODE_ID_BYTES);
+        assert_eq!(NodeId::acquire(), first);
+    }
+
+    #[test]
+    fn prefers_the_mac_address() {
+        match NodeId::from_mac_address() {
+            Some(mac) => assert_eq!(NodeId::acquire(), mac),
+            None => assert!(NodeId::acquire().is_multicast()),
+        }
+    }
+}
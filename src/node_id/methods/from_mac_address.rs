@@ -0,0 +1,58 @@
+use crate::NodeId;
+
+impl NodeId {
+    /// Reads the MAC address of a network interface of this machine, or
+    /// returns `None` if none is found.
+    ///
+    /// Interfaces are tried in name order, and addresses that are all zero
+    /// (such as loopback) or have the multicast bit set are skipped, so the
+    /// result is a unicast address that [`NodeId::is_multicast`] reports as
+    /// such. Detection is implemented for Linux, through `/sys/class/net`;
+    /// on other platforms this always returns `None`.
+    ///
+    /// A UUID carrying this node ID reveals the address of the machine that
+    /// generated it.
+    #[must_use]
+    pub fn from_mac_address() -> Option<Self> {
+        #[cfg(target_os = "linux")]
+        {
+            let mut interfaces: Vec<_> = std::fs::read_dir("/sys/class/net")
+                .ok()?
+                .filter_map(Result::ok)
+                .map(|entry| entry.path())
+                .collect();
+
+            interfaces.sort();
+
+            interfaces.into_iter().find_map(|interface| {
+                let address = std::fs::read_to_string(interface.join("address")).ok()?;
+                let node_id: Self = address.trim().parse().ok()?;
+
+                (node_id.bytes != [0; 6] && !node_id.is_multicast()).then_some(node_id)
+            })
+        }
+
+        #[cfg(not(target_os = "linux"))]
+        {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::NodeId;
+
+    #[test]
+    fn detected_addresses_are_unicast_and_non_zero() {
+        if let Some(node_id) = NodeId::from_mac_address() {
+            assert!(!node_id.is_multicast());
+            assert_ne!(node_id.bytes, [0; 6]);
+        }
+    }
+
+    #[test]
+    fn detection_is_deterministic() {
+        assert_eq!(NodeId::from_mac_address(), NodeId::from_mac_address());
+    }
+}
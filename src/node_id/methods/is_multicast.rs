@@ -0,0 +1,30 @@
+use crate::NodeId;
+
+impl NodeId {
+    /// Returns `true` if the multicast bit (the least significant bit of the
+    /// first byte) is set.
+    ///
+    /// RFC 9562 §6.10 requires randomly generated node IDs to set this bit,
+    /// which no unicast IEEE 802 MAC address has, so a set bit marks a node
+    /// ID that is not a hardware address.
+    #[must_use]
+    pub const fn is_multicast(&self) -> bool {
+        self.bytes[0] & 0x01 != 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::NodeId;
+
+    #[test]
+    fn reads_the_lsb_of_the_first_byte() {
+        assert!(NodeId::from_bytes([0x01, 0, 0, 0, 0, 0]).is_multicast());
+        assert!(!NodeId::from_bytes([0xFE, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF]).is_multicast());
+    }
+
+    #[test]
+    fn random_node_ids_are_multicast() {
+        assert!(NodeId::random().is_multicast());
+    }
+}
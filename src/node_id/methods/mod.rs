@@ -1,6 +1,9 @@
+mod acquire;
 mod as_bytes;
 mod as_bytes_mut;
 mod from_bytes;
+mod from_mac_address;
 mod into_bytes;
+mod is_multicast;
 mod random;
 mod to_bytes;
@@ -24,10 +24,12 @@ impl Default for State {
 
         Self {
             last_ts: floor,
-            node_id: NodeId::random(),
+            node_id: NodeId::acquire(),
             seq: random(),
             stalled: 0,
+            borrowed: 0,
             seq_v2: random(),
+            v7_counter: 0,
         }
     }
 }
@@ -36,7 +38,7 @@ impl Default for State {
 mod tests {
     use std::time::UNIX_EPOCH;
 
-    use crate::{State, UUID};
+    use crate::{NodeId, State, UUID};
 
     #[test]
     fn fresh_state_floor_is_tick_representable_and_pre_unix() {
@@ -51,4 +53,10 @@ mod tests {
             "the floor must be representable as a 60-bit tick count"
         );
     }
+
+    #[test]
+    fn fresh_states_share_the_acquired_node() {
+        assert_eq!(State::default().node_id, NodeId::acquire());
+        assert_eq!(State::default().node_id, State::default().node_id);
+    }
 }
@@ -0,0 +1,33 @@
+use std::time::SystemTime;
+
+use crate::State;
+
+impl State {
+    /// Returns the timestamp of the most recently issued tick.
+    ///
+    /// This is shared by every [`State`]-backed time-based generator, and may
+    /// run ahead of the wall clock while the clock stands still or moves
+    /// backward.
+    #[must_use]
+    pub const fn last_ts(&self) -> SystemTime {
+        self.last_ts
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use crate::State;
+
+    #[test]
+    fn reports_the_issued_timestamp() {
+        let mut state = State::default();
+        let now = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+
+        let issued = state.next_v7(now);
+
+        assert_eq!(issued, now);
+        assert_eq!(state.last_ts(), issued);
+    }
+}
@@ -1,6 +1,9 @@
 mod is_adoptable;
+mod last_ts;
 mod next;
 mod next_v2;
 mod next_v7;
+mod next_v7_counter;
 mod node_id;
+mod reset;
 mod set_node_id;
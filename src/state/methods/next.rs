@@ -1,6 +1,6 @@
 use std::time::SystemTime;
 
-use crate::{methods::TICK, State};
+use crate::{methods::TICK, State, UuidConstructionError};
 
 /// The number of clock-sequence values that may be issued for one tick.
 /// The DCOM variant overwrites the top 3 of the 14 sequence bits, keeping
@@ -8,6 +8,16 @@ use crate::{methods::TICK, State};
 const TICK_CAPACITY: u16 = 1 << 13;
 
 impl State {
+    /// The maximum number of 100 ns ticks [`State::next`] borrows from the
+    /// future before a clock reading is adopted again: 10 000 ticks, or one
+    /// millisecond.
+    ///
+    /// With 2¹³ clock-sequence values per tick, a frozen clock therefore
+    /// yields 2¹³ × 10 001 = 81 928 192 distinct (tick, clock sequence) pairs
+    /// from the adopted tick and the borrowed ones before
+    /// [`UuidConstructionError::RateExceeded`] is returned.
+    pub const MAX_BORROWED_TICKS: u32 = 10_000;
+
     /// This method returns the next time-based [`UUID`](crate::UUID)'s
     /// timestamp and clock sequence, never issuing the same (100 ns tick,
     /// clock sequence) pair twice, even under a frozen, coarse, or
@@ -31,21 +41,37 @@ impl State {
     /// instead, so generation continues from the last issued tick and resumes
     /// normal adoption once the clock recovers.
     ///
+    /// # Errors
+    ///
+    /// - [`UuidConstructionError::RateExceeded`] is returned, leaving this
+    ///   [`State`] unchanged, once [`State::MAX_BORROWED_TICKS`] ticks have
+    ///   been borrowed since a clock reading was last adopted and the current
+    ///   tick is exhausted. Generation resumes once the clock advances.
+    ///
     /// # Usage
     ///
     /// ```
     /// use ps_uuid::STATE;
     /// use std::time::SystemTime;
     ///
-    /// let (timestamp, clock_seq) = STATE.lock().next(SystemTime::now());
+    /// let (timestamp, clock_seq) = STATE.lock().next(SystemTime::now()).unwrap();
     /// ```
-    pub fn next(&mut self, timestamp: SystemTime) -> (SystemTime, u16) {
+    pub fn next(
+        &mut self,
+        timestamp: SystemTime,
+    ) -> Result<(SystemTime, u16), UuidConstructionError> {
         if timestamp > self.last_ts + TICK && Self::is_adoptable(timestamp) {
             // The clock advanced past the current tick and the reading is
             // representable: open a new window.
             self.last_ts = timestamp;
             self.stalled = 0;
+            self.borrowed = 0;
         } else {
+            if self.stalled + 1 >= TICK_CAPACITY && self.borrowed >= Self::MAX_BORROWED_TICKS {
+                // Issuing another value would borrow one tick too many.
+                return Err(UuidConstructionError::RateExceeded);
+            }
+
             // Same tick, a backward-moving clock, or an unrepresentable
             // reading: advance the sequence.
             self.seq = (self.seq.wrapping_add(1)) & 0x3FFF;
@@ -56,15 +82,17 @@ impl State {
                 // the next tick instead of repeating one.
                 self.last_ts += TICK;
                 self.stalled = 0;
+                self.borrowed += 1;
             }
         }
 
-        (self.last_ts, self.seq)
+        Ok((self.last_ts, self.seq))
     }
 }
 
 #[cfg(test)]
 mod tests {
+    #![allow(clippy::expect_used)]
     use std::{
         collections::HashSet,
         time::{Duration, SystemTime, UNIX_EPOCH},
@@ -80,7 +108,9 @@ mod tests {
         let mut previous = UNIX_EPOCH;
 
         for _ in 0..100_000 {
-            let (timestamp, seq) = guard.next(SystemTime::now());
+            let (timestamp, seq) = guard
+                .next(SystemTime::now())
+                .expect("the borrowing limit must not be reached");
 
             assert!(timestamp >= previous, "Timestamps must never decrease.");
             assert!(
@@ -104,7 +134,9 @@ mod tests {
             node_id: NodeId::random(),
             seq: 0,
             stalled: 0,
+            borrowed: 0,
             seq_v2: 0,
+            v7_counter: 0,
         };
 
         let frozen = UNIX_EPOCH + Duration::from_secs(1_000_000_000);
@@ -114,7 +146,9 @@ mod tests {
         let mut previous = UNIX_EPOCH;
 
         for _ in 0..20_000 {
-            let (timestamp, seq) = state.next(frozen);
+            let (timestamp, seq) = state
+                .next(frozen)
+                .expect("the borrowing limit must not be reached");
 
             assert!(timestamp >= previous, "Timestamps must never decrease.");
             assert!(
@@ -141,12 +175,16 @@ mod tests {
             node_id: NodeId::random(),
             seq: 0,
             stalled: 0,
+            borrowed: 0,
             seq_v2: 0,
+            v7_counter: 0,
         };
 
         let bogus = UNIX_EPOCH + Duration::from_secs(200_000_000_000);
 
-        let (timestamp, seq) = state.next(bogus);
+        let (timestamp, seq) = state
+            .next(bogus)
+            .expect("the borrowing limit must not be reached");
 
         assert_eq!(timestamp, UNIX_EPOCH);
         assert_eq!(seq, 1);
@@ -161,20 +199,26 @@ mod tests {
             node_id: NodeId::random(),
             seq: 0,
             stalled: 0,
+            borrowed: 0,
             seq_v2: 0,
+            v7_counter: 0,
         };
 
         let bogus = UNIX_EPOCH + Duration::from_secs(200_000_000_000);
 
         for _ in 0..10 {
-            let (timestamp, _) = state.next(bogus);
+            let (timestamp, _) = state
+                .next(bogus)
+                .expect("the borrowing limit must not be reached");
 
             assert!(UUID::system_time_to_ticks(timestamp).is_ok());
         }
 
         let sane = UNIX_EPOCH + Duration::from_secs(1_000_000_000);
 
-        let (timestamp, _) = state.next(sane);
+        let (timestamp, _) = state
+            .next(sane)
+            .expect("the borrowing limit must not be reached");
 
         assert_eq!(timestamp, sane);
     }
@@ -188,7 +232,9 @@ mod tests {
             node_id: NodeId::random(),
             seq: 0,
             stalled: 0,
+            borrowed: 0,
             seq_v2: 0,
+            v7_counter: 0,
         };
 
         // 2^60 - 1 ticks after 1582-10-15, expressed relative to UNIX_EPOCH:
@@ -196,11 +242,15 @@ mod tests {
         let last_within = UNIX_EPOCH + Duration::new(103_072_857_660, 684_697_500);
         let first_beyond = last_within + Duration::from_nanos(100);
 
-        let (timestamp, _) = state.next(last_within);
+        let (timestamp, _) = state
+            .next(last_within)
+            .expect("the borrowing limit must not be reached");
 
         assert_eq!(timestamp, last_within);
 
-        let (timestamp, _) = state.next(first_beyond);
+        let (timestamp, _) = state
+            .next(first_beyond)
+            .expect("the borrowing limit must not be reached");
 
         assert_eq!(timestamp, last_within);
     }
@@ -214,7 +264,9 @@ mod tests {
             node_id: NodeId::random(),
             seq: 0,
             stalled: 0,
+            borrowed: 0,
             seq_v2: 0,
+            v7_counter: 0,
         };
 
         let now = UNIX_EPOCH + Duration::from_secs(1_000_000_000);
@@ -225,7 +277,9 @@ mod tests {
 
         for _ in 0..100 {
             for timestamp in [now, past] {
-                let (issued, seq) = state.next(timestamp);
+                let (issued, seq) = state
+                    .next(timestamp)
+                    .expect("the borrowing limit must not be reached");
 
                 assert!(issued >= previous, "Timestamps must never decrease.");
                 assert!(
@@ -237,4 +291,105 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn millisecond_clock_yields_unique_v1_uuids() {
+        // A clock with millisecond resolution reports the same reading for
+        // every call within the millisecond.
+        let mut state = State {
+            last_ts: UNIX_EPOCH,
+            node_id: NodeId::random(),
+            seq: 0x3FFF,
+            stalled: 0,
+            borrowed: 0,
+            seq_v2: 0,
+            v7_counter: 0,
+        };
+
+        let frozen_ms = UNIX_EPOCH + Duration::from_millis(1_700_000_000_123);
+        let mut uuids = HashSet::with_capacity(20_000);
+
+        for _ in 0..20_000 {
+            let (timestamp, seq) = state
+                .next(frozen_ms)
+                .expect("the borrowing limit must not be reached");
+            let uuid = UUID::new_v1(timestamp, seq, state.node_id().bytes)
+                .expect("timestamp must be encodable");
+
+            assert!(
+                timestamp < frozen_ms + Duration::from_millis(1),
+                "20 000 UUIDs must fit within the millisecond"
+            );
+            assert!(uuids.insert(uuid), "Duplicate UUID generated!");
+        }
+    }
+
+    /// A frozen clock is served by borrowing ticks until
+    /// `MAX_BORROWED_TICKS` is reached, never repeating a (tick, 13-bit clock
+    /// sequence) pair; the next call fails without disturbing the state.
+    #[test]
+    fn frozen_clock_is_served_until_the_borrowing_limit() {
+        let mut state = State {
+            last_ts: UNIX_EPOCH,
+            node_id: NodeId::random(),
+            seq: 0,
+            stalled: 0,
+            borrowed: 0,
+            seq_v2: 0,
+            v7_counter: 0,
+        };
+
+        let frozen = UNIX_EPOCH + Duration::from_secs(1_000_000_000);
+        let limit = (u64::from(State::MAX_BORROWED_TICKS) + 1) << 13;
+
+        let (mut tick, mut seq) = state
+            .next(frozen)
+            .expect("the borrowing limit must not be reached");
+        let mut issued_in_tick = 1u32;
+        let mut ticks = 1u32;
+
+        assert_eq!(tick, frozen);
+
+        for _ in 1..limit {
+            let (next_tick, next_seq) = state
+                .next(frozen)
+                .expect("the borrowing limit must not be reached");
+
+            if next_tick == tick {
+                // Consecutive sequence values within one tick are distinct
+                // modulo 2¹³ as long as the tick holds at most 2¹³ of them.
+                issued_in_tick += 1;
+                assert!(issued_in_tick <= 1 << 13, "A tick was overfilled.");
+            } else {
+                assert_eq!(next_tick, tick + Duration::from_nanos(100));
+                issued_in_tick = 1;
+                ticks += 1;
+            }
+
+            assert_eq!(next_seq, (seq + 1) & 0x3FFF);
+
+            (tick, seq) = (next_tick, next_seq);
+        }
+
+        assert_eq!(ticks, State::MAX_BORROWED_TICKS + 1);
+
+        let snapshot = state;
+
+        assert_eq!(
+            state.next(frozen),
+            Err(crate::UuidConstructionError::RateExceeded)
+        );
+        assert_eq!(state, snapshot, "A refused call must not change the state.");
+
+        // Once the clock passes the borrowed ticks, generation resumes.
+        let later = tick + Duration::from_micros(1);
+
+        assert_eq!(
+            state
+                .next(later)
+                .expect("the borrowing limit must reset on adoption")
+                .0,
+            later
+        );
+    }
 }
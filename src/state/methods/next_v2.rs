@@ -36,6 +36,7 @@ impl State {
             // `next` maintains.
             self.last_ts = timestamp;
             self.stalled = 0;
+            self.borrowed = 0;
         }
 
         self.seq_v2 = self.seq_v2.wrapping_add(1) & 0x3F;
@@ -46,6 +47,7 @@ impl State {
 
 #[cfg(test)]
 mod tests {
+    #![allow(clippy::expect_used)]
     use std::{
         collections::HashSet,
         time::{Duration, UNIX_EPOCH},
@@ -65,7 +67,9 @@ mod tests {
             node_id: NodeId::random(),
             seq: 0,
             stalled: 0,
+            borrowed: 0,
             seq_v2: 0,
+            v7_counter: 0,
         };
 
         let frozen = UNIX_EPOCH + Duration::from_secs(1_000_000_000);
@@ -93,7 +97,9 @@ mod tests {
             node_id: NodeId::random(),
             seq: 0,
             stalled: 0,
+            borrowed: 0,
             seq_v2: 0,
+            v7_counter: 0,
         };
 
         let frozen = UNIX_EPOCH + Duration::from_secs(1_000_000_000);
@@ -122,7 +128,9 @@ mod tests {
             node_id: NodeId::random(),
             seq: 0,
             stalled: 0,
+            borrowed: 0,
             seq_v2: 0,
+            v7_counter: 0,
         };
 
         let bogus = UNIX_EPOCH + Duration::from_secs(200_000_000_000);
@@ -147,7 +155,9 @@ mod tests {
             node_id: NodeId::random(),
             seq: 0,
             stalled: 0,
+            borrowed: 0,
             seq_v2: 0,
+            v7_counter: 0,
         };
 
         let clean_bits: Vec<u16> = (0..64)
@@ -159,14 +169,18 @@ mod tests {
             node_id: NodeId::random(),
             seq: 0,
             stalled: 0,
+            borrowed: 0,
             seq_v2: 0,
+            v7_counter: 0,
         };
 
         let mut noisy_bits = Vec::with_capacity(64);
 
         for i in 0..64 {
             for _ in 0..(i * 7 + 3) {
-                noisy.next(frozen);
+                noisy
+                    .next(frozen)
+                    .expect("the borrowing limit must not be reached");
             }
 
             noisy_bits.push(surviving_bits(noisy.next_v2(frozen).1));
@@ -23,8 +23,9 @@ impl State {
     /// let next_ts = STATE.lock().next_v7(SystemTime::now());
     /// ```
     pub fn next_v7(&mut self, timestamp: SystemTime) -> SystemTime {
-        // `stalled` is intentionally left alone: a stale count can only
-        // shrink the next tick's clock-sequence budget, never enlarge it.
+        // `stalled` and `borrowed` are intentionally left alone: stale counts
+        // can only shrink the clock-sequence and borrowing budgets of
+        // `next`, never enlarge them.
         let candidate = self.last_ts + FIDELITY;
 
         let timestamp = if timestamp > candidate && Self::is_adoptable(timestamp) {
@@ -71,7 +72,9 @@ mod tests {
             node_id: NodeId::random(),
             seq: 0,
             stalled: 0,
+            borrowed: 0,
             seq_v2: 0,
+            v7_counter: 0,
         };
 
         let bogus = UNIX_EPOCH + Duration::from_secs(200_000_000_000);
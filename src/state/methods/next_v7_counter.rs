@@ -0,0 +1,39 @@
+use crate::State;
+
+impl State {
+    /// Issues the next [`V7Mode::Counter`] value for the millisecond `ms`:
+    /// zero for a new millisecond, else one more than the last value.
+    ///
+    /// [`State::next_v7`] advances by at least 256 ns per call, so at most
+    /// 3 907 timestamps fall within one millisecond and the 12-bit counter
+    /// cannot overflow into the next.
+    ///
+    /// [`V7Mode::Counter`]: crate::V7Mode::Counter
+    pub(crate) const fn next_v7_counter(&mut self, ms: u64) -> u16 {
+        let last = self.v7_counter;
+        let next = if last >> 12 == ms { last + 1 } else { ms << 12 };
+
+        self.v7_counter = next;
+
+        #[allow(clippy::cast_possible_truncation)]
+        let counter = (next & 0x0FFF) as u16;
+
+        counter
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::State;
+
+    #[test]
+    fn counter_restarts_each_millisecond() {
+        let mut state = State::default();
+
+        assert_eq!(state.next_v7_counter(5), 0);
+        assert_eq!(state.next_v7_counter(5), 1);
+        assert_eq!(state.next_v7_counter(5), 2);
+        assert_eq!(state.next_v7_counter(6), 0);
+        assert_eq!(state.next_v7_counter(6), 1);
+    }
+}
@@ -0,0 +1,81 @@
+use crate::{methods::TICK, NodeId, State};
+
+impl State {
+    /// Redraws both clock sequences, as [`State::default`] does, and the
+    /// node identifier, without rewinding the timestamp.
+    ///
+    /// The new node identifier is always [`NodeId::random`], never the
+    /// cached [`NodeId::acquire`] that [`State::default`] uses, so it
+    /// differs from the previous one whatever its origin; a node identifier
+    /// set by [`State::set_node_id`] is discarded as well. The last
+    /// issued timestamp is kept and advanced by one tick, so the sequence
+    /// values issued afterwards start on a tick that has not been used yet:
+    /// even a DCOM UUID, whose node ID the caller supplies and which a fresh
+    /// node ID therefore cannot disambiguate, never repeats across a reset.
+    /// The [`V7Mode::Counter`] value is kept too, so version-7 UUIDs issued
+    /// in the same millisecond stay in issue order.
+    ///
+    /// [`V7Mode::Counter`]: crate::V7Mode::Counter
+    pub fn reset(&mut self) {
+        *self = Self {
+            last_ts: self.last_ts + TICK,
+            node_id: NodeId::random(),
+            v7_counter: self.v7_counter,
+            ..Self::default()
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use crate::{methods::TICK, NodeId, State};
+
+    #[test]
+    fn reset_redraws_everything_but_the_timestamp() {
+        let last_ts = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        let node_id = NodeId::from_bytes([0x00, 0x1B, 0x63, 0x84, 0x45, 0xE6]);
+
+        let mut state = State {
+            last_ts,
+            node_id,
+            seq: 7,
+            stalled: 100,
+            borrowed: 5,
+            seq_v2: 9,
+            v7_counter: 3,
+        };
+
+        state.reset();
+
+        assert_eq!(state.last_ts, last_ts + TICK);
+        assert_ne!(state.node_id, node_id);
+        assert!(state.node_id.is_multicast());
+        assert_eq!(state.stalled, 0);
+        assert_eq!(state.borrowed, 0);
+        assert_eq!(state.v7_counter, 3);
+    }
+
+    #[test]
+    fn reset_never_reissues_a_tick() {
+        let last_ts = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+
+        let mut state = State {
+            last_ts,
+            node_id: NodeId::random(),
+            seq: 0,
+            stalled: 0,
+            borrowed: 0,
+            seq_v2: 0,
+            v7_counter: 0,
+        };
+
+        state.reset();
+
+        // A frozen clock keeps the state on its current tick.
+        let (timestamp, _) = state.next(last_ts).expect("must issue");
+
+        assert!(timestamp > last_ts);
+    }
+}
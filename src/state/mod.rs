@@ -17,17 +17,26 @@ pub struct State {
     /// never move the value out of the range the time-based constructors
     /// accept.
     last_ts: SystemTime,
-    /// The node identifier embedded in generated UUIDs.
+    /// The node identifier embedded in generated UUIDs; initially the
+    /// process-wide [`NodeId::acquire`].
     node_id: NodeId,
     /// The clock sequence, incremented to disambiguate UUIDs sharing a timestamp.
     seq: u16,
     /// The number of clock-sequence values issued for the current tick.
     stalled: u16,
+    /// The number of ticks borrowed from the future since a clock reading was
+    /// last adopted, capped at [`State::MAX_BORROWED_TICKS`].
+    borrowed: u32,
     /// A dedicated counter for the six clock-sequence bits a version-2 UUID
     /// retains. Stepped only by [`State::next_v2`], so traffic through
     /// [`State::next`] cannot realign the surviving bits and duplicate a
     /// version-2 UUID within a timestamp window.
     seq_v2: u8,
+    /// The last [`V7Mode::Counter`] value issued, packed as
+    /// `unix_ts_ms << 12 | counter`.
+    ///
+    /// [`V7Mode::Counter`]: crate::V7Mode::Counter
+    v7_counter: u64,
 }
 
 /// The process-wide [`State`] guarding time-based UUID generation.
@@ -42,5 +51,11 @@ pub struct State {
 /// node ID does not protect DCOM generation, which embeds a caller-supplied
 /// node ID: forked callers of [`UUID::gen_dcom`](crate::UUID::gen_dcom) must
 /// pass the child a distinct node ID instead.
+///
+/// The lock is a [`parking_lot::Mutex`], which does not poison: a panic in a
+/// thread holding it releases the lock, and every later `lock()` succeeds,
+/// so the generators never surface a `PoisonError`.
+/// [`UUID::reset_global_state`](crate::UUID::reset_global_state) reseeds the
+/// state if it is ever suspected to be compromised.
 pub static STATE: std::sync::LazyLock<Arc<Mutex<State>>> =
     std::sync::LazyLock::new(|| Arc::new(Mutex::new(State::default())));
@@ -0,0 +1,28 @@
+use std::fmt;
+
+use crate::Variant;
+
+impl fmt::Display for Variant {
+    /// Writes the variant's name: `NCS`, `OSF`, `DCOM`, or `Reserved`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::NCS => "NCS",
+            Self::OSF => "OSF",
+            Self::DCOM => "DCOM",
+            Self::Reserved => "Reserved",
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Variant;
+
+    #[test]
+    fn names() {
+        assert_eq!(Variant::NCS.to_string(), "NCS");
+        assert_eq!(Variant::OSF.to_string(), "OSF");
+        assert_eq!(Variant::DCOM.to_string(), "DCOM");
+        assert_eq!(Variant::Reserved.to_string(), "Reserved");
+    }
+}
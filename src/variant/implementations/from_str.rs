@@ -0,0 +1,53 @@
+use std::str::FromStr;
+
+use crate::{Variant, VariantParseError};
+
+impl FromStr for Variant {
+    type Err = VariantParseError;
+
+    /// Accepts the names [`Display`](std::fmt::Display) writes, plus the
+    /// aliases `RFC4122` for [`Variant::OSF`] and `Microsoft` for
+    /// [`Variant::DCOM`], all case-insensitively.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        [
+            ("NCS", Self::NCS),
+            ("OSF", Self::OSF),
+            ("RFC4122", Self::OSF),
+            ("DCOM", Self::DCOM),
+            ("Microsoft", Self::DCOM),
+            ("Reserved", Self::Reserved),
+        ]
+        .into_iter()
+        .find_map(|(name, variant)| name.eq_ignore_ascii_case(s).then_some(variant))
+        .ok_or(VariantParseError)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Variant, VariantParseError};
+
+    #[test]
+    fn display_roundtrips() {
+        for variant in [Variant::NCS, Variant::OSF, Variant::DCOM, Variant::Reserved] {
+            assert_eq!(variant.to_string().parse(), Ok(variant));
+        }
+    }
+
+    #[test]
+    fn accepts_aliases_in_any_case() {
+        assert_eq!("rfc4122".parse(), Ok(Variant::OSF));
+        assert_eq!("Osf".parse(), Ok(Variant::OSF));
+        assert_eq!("MICROSOFT".parse(), Ok(Variant::DCOM));
+        assert_eq!("dcom".parse(), Ok(Variant::DCOM));
+        assert_eq!("ncs".parse(), Ok(Variant::NCS));
+        assert_eq!("RESERVED".parse(), Ok(Variant::Reserved));
+    }
+
+    #[test]
+    fn rejects_unknown_names() {
+        for s in ["", "RFC 4122", "OSF ", "variant", "4"] {
+            assert_eq!(s.parse::<Variant>(), Err(VariantParseError), "{s:?}");
+        }
+    }
+}
@@ -0,0 +1,2 @@
+mod display;
+mod from_str;
@@ -3,6 +3,23 @@ use crate::Variant;
 impl Variant {
     /// Returns the bitmask that preserves the payload bits of the variant byte,
     /// clearing the bits reserved for the variant itself.
+    ///
+    /// The variant byte is byte 8 of the UUID (the high byte of the clock
+    /// sequence). Setting a variant is `byte & variant.bitmask() |
+    /// variant.prefix()`, which is what [`UUID::with_variant`] does; see
+    /// [`Variant::prefix`].
+    ///
+    /// [`UUID::with_variant`]: crate::UUID::with_variant
+    ///
+    /// ```
+    /// use ps_uuid::{Variant, UUID};
+    ///
+    /// let uuid = UUID::max();
+    /// let byte = uuid.as_bytes()[8] & Variant::DCOM.bitmask() | Variant::DCOM.prefix();
+    ///
+    /// assert_eq!(byte, 0xDF);
+    /// assert_eq!(byte, uuid.with_variant(Variant::DCOM).as_bytes()[8]);
+    /// ```
     #[must_use]
     pub const fn bitmask(self) -> u8 {
         match self {
@@ -2,6 +2,12 @@ use crate::Variant;
 
 impl Variant {
     /// Returns the bit prefix that identifies the variant in the variant byte.
+    ///
+    /// The prefix occupies exactly the bits [`Variant::bitmask`] clears in
+    /// byte 8, so OR-ing it into a masked byte sets the variant without
+    /// touching the payload bits: `0b0xxx_xxxx` for NCS, `0b10xx_xxxx` for
+    /// OSF, `0b110x_xxxx` for DCOM, and `0b111x_xxxx` for the reserved
+    /// variant.
     #[must_use]
     pub const fn prefix(self) -> u8 {
         match self {
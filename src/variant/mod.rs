@@ -1,3 +1,4 @@
+mod implementations;
 mod methods;
 
 /// The variant of a UUID, which determines the layout of its bits.